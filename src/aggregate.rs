@@ -0,0 +1,133 @@
+//! Aggregations of numeric values.
+//!
+//! An `Aggregation` reduces the values of a group of rows to one value. Empty fields are not taken into account. `First`,
+//! `Last` and `NUnique` work with the fields as they are written, so they can be used with columns of any type: `First` and
+//! `Last` keep the first and last non empty field and `NUnique` counts the distinct ones. The other aggregations only use the
+//! values which can be parsed as f64, `Count` is the number of those values. A group without values has no result, except
+//! for `Count` and `NUnique` which are 0.
+
+use crate::stats::{self, QuantileMethod};
+use std::collections::HashSet;
+use std::fmt;
+
+/// A function to reduce a group of values to one value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Aggregation {
+    Sum,
+    Mean,
+    Median,
+    Min,
+    Max,
+    Count,
+    First,
+    Last,
+    NUnique,
+}
+
+impl fmt::Display for Aggregation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nombre = match self {
+            Aggregation::Sum => "sum",
+            Aggregation::Mean => "mean",
+            Aggregation::Median => "median",
+            Aggregation::Min => "min",
+            Aggregation::Max => "max",
+            Aggregation::Count => "count",
+            Aggregation::First => "first",
+            Aggregation::Last => "last",
+            Aggregation::NUnique => "n_unique",
+        };
+        write!(f, "{}", nombre)
+    }
+}
+
+/// Running state of one aggregation of a group of values. The fields and the values are only kept by the
+/// aggregations which need them, `First`, `Last`, `NUnique` and `Median`.
+#[derive(Debug, Clone)]
+pub(crate) struct Accumulator {
+    aggregation: Aggregation,
+    count: usize,
+    sum: f64,
+    min: Option<f64>,
+    max: Option<f64>,
+    first: Option<String>,
+    last: Option<String>,
+    values: Vec<f64>,
+    unique: HashSet<String>,
+}
+
+impl Accumulator {
+    /// Creates an empty accumulator for an aggregation.
+    pub(crate) fn new(aggregation: Aggregation) -> Accumulator {
+        Accumulator {
+            aggregation,
+            count: 0,
+            sum: 0.0,
+            min: None,
+            max: None,
+            first: None,
+            last: None,
+            values: Vec::new(),
+            unique: HashSet::new(),
+        }
+    }
+
+    /// Adds a non empty field to the group.
+    pub(crate) fn push(&mut self, cell: &str) {
+        match self.aggregation {
+            Aggregation::NUnique => {
+                if !self.unique.contains(cell) {
+                    self.unique.insert(String::from(cell));
+                }
+                return
+            },
+            Aggregation::First => {
+                if self.first.is_none() {
+                    self.first = Some(String::from(cell));
+                }
+                return
+            },
+            Aggregation::Last => {
+                self.last = Some(String::from(cell));
+                return
+            },
+            _ => (),
+        }
+
+        let value = match cell.parse::<f64>() {
+            Ok(value) => value,
+            Err(_) => return,
+        };
+
+        self.count += 1;
+        self.sum += value;
+        self.min = Some(self.min.map_or(value, |min| min.min(value)));
+        self.max = Some(self.max.map_or(value, |max| max.max(value)));
+        if self.aggregation == Aggregation::Median {
+            self.values.push(value);
+        }
+    }
+
+    /// Returns the result of the aggregation as a field, None if the group has no values and the aggregation needs them.
+    pub(crate) fn result(&self) -> Option<String> {
+        let valor = match self.aggregation {
+            Aggregation::Sum if self.count > 0 => Some(self.sum),
+            Aggregation::Sum => None,
+            Aggregation::Count => Some(self.count as f64),
+            Aggregation::NUnique => Some(self.unique.len() as f64),
+            Aggregation::Mean if self.count > 0 => Some(self.sum / self.count as f64),
+            Aggregation::Mean => None,
+            Aggregation::Median => {
+                let mut ordenados = self.values.clone();
+                ordenados.sort_by(|a, b| a.total_cmp(b));
+                stats::quantile_sorted(&ordenados, 0.5, QuantileMethod::Linear)
+            },
+            Aggregation::Min => self.min,
+            Aggregation::Max => self.max,
+            Aggregation::First => return self.first.clone(),
+            Aggregation::Last => return self.last.clone(),
+        };
+
+        valor.map(|valor| valor.to_string())
+    }
+}
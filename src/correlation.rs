@@ -0,0 +1,223 @@
+//! Correlation and covariance matrices.
+//!
+//! `RawFrame::corr` and `RawFrame::cov` compare every pair of numeric columns and return a labelled square `Matrix`. The
+//! values are parsed as f64, empty fields, values which are not possible to parse and `NaN` values are missing values.
+//! With `MissingPolicy::Pairwise` each pair of columns uses all the records where both values exist, with
+//! `MissingPolicy::Listwise` only the records without missing values in any of the columns are used. When no columns are
+//! given, the columns inferred as integer or float columns are used.
+//!
+//! The covariance is the sample one, divided by `n - 1`. A cell is None when there are less than two records or, for the
+//! correlation, when one of the columns is constant.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::correlation::CorrMethod;
+//!
+//! let datos: RawFrame = "x,y,z\n1,2,9\n2,4,\n3,6,1\n4,8,0\n".parse().unwrap();
+//!
+//! let matriz = datos.corr(&["x", "y", "z"], CorrMethod::Spearman).unwrap();
+//! println!("{}", matriz);
+//!
+//! assert_eq!(matriz.get("x", "y"), Some(1.0));
+//! assert_eq!(matriz.get("z", "x"), Some(-1.0));
+//! ```
+
+use crate::dataframe::DataType;
+use std::fmt;
+
+/// The correlation coefficient.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CorrMethod {
+    /// Linear correlation of the values.
+    Pearson,
+    /// Pearson correlation of the ranks of the values, ties get the mean of their ranks.
+    Spearman,
+}
+
+/// Which records are used when there are missing values.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MissingPolicy {
+    /// Each pair of columns uses the records where both values exist.
+    Pairwise,
+    /// All the pairs use only the records where all the values exist.
+    Listwise,
+}
+
+/// A square matrix with the names of the columns as labels of its rows and columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Matrix {
+    pub labels: Vec<String>,
+    /// The values by row, None where the value is not defined.
+    pub values: Vec<Vec<Option<f64>>>,
+}
+
+impl Matrix {
+    /// Returns the value for a pair of labels, None if a label does not exists or the value is not defined.
+    ///
+    /// # Arguments
+    ///
+    /// * `row` - A string slice with the label of the row
+    /// * `column` - A string slice with the label of the column
+    pub fn get(&self, row: &str, column: &str) -> Option<f64> {
+        let i = self.labels.iter().position(|label| label == row)?;
+        let j = self.labels.iter().position(|label| label == column)?;
+
+        self.values[i][j]
+    }
+
+    /// Returns a RawFrame with a `column` column with the labels followed by one column per label.
+    pub fn to_rawframe(&self) -> crate::RawFrame {
+        let mut columns = csv::StringRecord::new();
+        columns.push_field("column");
+        for label in &self.labels {
+            columns.push_field(label);
+        }
+
+        let records = self.labels.iter().zip(self.values.iter()).map(|(label, fila)| {
+            let mut record = csv::StringRecord::new();
+            record.push_field(label);
+            for valor in fila {
+                record.push_field(&valor.map_or(String::new(), |valor| valor.to_string()));
+            }
+            record
+        }).collect();
+
+        crate::RawFrame { columns, records }
+    }
+}
+
+impl fmt::Display for Matrix {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let celdas: Vec<Vec<String>> = self.values.iter()
+            .map(|fila| fila.iter().map(|valor| valor.map_or(String::new(), |valor| format!("{:.4}", valor))).collect())
+            .collect();
+
+        let primera = self.labels.iter().map(|label| label.chars().count()).max().unwrap_or(0);
+        let anchos: Vec<usize> = self.labels.iter().enumerate()
+            .map(|(j, label)| celdas.iter().map(|fila| fila[j].len()).chain(Some(label.chars().count())).max().unwrap_or(0))
+            .collect();
+
+        write!(f, "{:primera$}", "", primera = primera)?;
+        for (label, ancho) in self.labels.iter().zip(anchos.iter()) {
+            write!(f, "  {:>ancho$}", label, ancho = ancho)?;
+        }
+        writeln!(f)?;
+
+        for (label, fila) in self.labels.iter().zip(celdas.iter()) {
+            write!(f, "{:<primera$}", label, primera = primera)?;
+            for (celda, ancho) in fila.iter().zip(anchos.iter()) {
+                write!(f, "  {:>ancho$}", celda, ancho = ancho)?;
+            }
+            writeln!(f)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the ranks of the values starting at 1, ties get the mean of their ranks.
+fn ranks(valores: &[f64]) -> Vec<f64> {
+    let mut orden: Vec<usize> = (0..valores.len()).collect();
+    orden.sort_by(|a, b| valores[*a].total_cmp(&valores[*b]));
+
+    let mut rangos = vec![0.0; valores.len()];
+    let mut inicio = 0;
+    while inicio < orden.len() {
+        let mut fin = inicio + 1;
+        while fin < orden.len() && valores[orden[fin]] == valores[orden[inicio]] {
+            fin += 1;
+        }
+        let rango = (inicio + fin + 1) as f64 / 2.0;
+        for n in &orden[inicio..fin] {
+            rangos[*n] = rango;
+        }
+        inicio = fin;
+    }
+
+    rangos
+}
+
+/// Returns the sample covariance and the sample variances of two sets of values, None with less than two values.
+fn covariance(x: &[f64], y: &[f64]) -> Option<(f64, f64, f64)> {
+    if x.len() < 2 {
+        return None
+    }
+
+    let n = x.len() as f64;
+    let media_x = x.iter().sum::<f64>() / n;
+    let media_y = y.iter().sum::<f64>() / n;
+
+    let (mut cov, mut var_x, mut var_y) = (0.0, 0.0, 0.0);
+    for (a, b) in x.iter().zip(y.iter()) {
+        cov += (a - media_x) * (b - media_y);
+        var_x += (a - media_x).powi(2);
+        var_y += (b - media_y).powi(2);
+    }
+
+    Some((cov / (n - 1.0), var_x / (n - 1.0), var_y / (n - 1.0)))
+}
+
+/// Returns the matrix of a statistic of every pair of columns. The statistic receives the values of both columns in the used records.
+fn matrix<F>(raw: &crate::RawFrame, columns: &[&str], missing: MissingPolicy, statistic: F) -> Result<Matrix, crate::RavenError>
+where F: Fn(usize, usize, &[f64], &[f64]) -> Option<f64>
+{
+    let schema = if columns.is_empty() { Some(raw.infer_schema(None)) } else { None };
+    let columns: Vec<&str> = match &schema {
+        Some(schema) => schema.columns.iter()
+            .filter(|col| col.data_type == DataType::Int || col.data_type == DataType::Float)
+            .map(|col| col.name.as_str())
+            .collect(),
+        None => columns.to_vec(),
+    };
+    let positions = raw.col_positions(&columns)?;
+
+    let datos: Vec<Vec<Option<f64>>> = positions.iter().map(|position| {
+        raw.records.iter()
+            .map(|record| record.get(*position).and_then(|cadena| cadena.parse::<f64>().ok()).filter(|valor| !valor.is_nan()))
+            .collect()
+    }).collect();
+
+    let completos: Vec<bool> = (0..raw.records.len())
+        .map(|n| missing == MissingPolicy::Pairwise || datos.iter().all(|columna| columna[n].is_some()))
+        .collect();
+
+    let mut values = vec![vec![None; columns.len()]; columns.len()];
+    for i in 0..columns.len() {
+        for j in i..columns.len() {
+            let (x, y): (Vec<f64>, Vec<f64>) = datos[i].iter().zip(datos[j].iter()).zip(completos.iter())
+                .filter_map(|((a, b), completo)| if *completo { Some(((*a)?, (*b)?)) } else { None })
+                .unzip();
+
+            let valor = statistic(i, j, &x, &y);
+            values[i][j] = valor;
+            values[j][i] = valor;
+        }
+    }
+
+    Ok(Matrix { labels: columns.iter().map(|col| String::from(*col)).collect(), values })
+}
+
+/// Returns the correlation matrix of the columns.
+pub(crate) fn corr(raw: &crate::RawFrame, columns: &[&str], method: CorrMethod, missing: MissingPolicy) -> Result<Matrix, crate::RavenError> {
+    matrix(raw, columns, missing, |i, j, x, y| {
+        let (cov, var_x, var_y) = match method {
+            CorrMethod::Pearson => covariance(x, y)?,
+            CorrMethod::Spearman => covariance(&ranks(x), &ranks(y))?,
+        };
+
+        if var_x == 0.0 || var_y == 0.0 {
+            None
+        } else if i == j {
+            Some(1.0)
+        } else {
+            Some((cov / (var_x * var_y).sqrt()).clamp(-1.0, 1.0))
+        }
+    })
+}
+
+/// Returns the covariance matrix of the columns.
+pub(crate) fn cov(raw: &crate::RawFrame, columns: &[&str], missing: MissingPolicy) -> Result<Matrix, crate::RavenError> {
+    matrix(raw, columns, missing, |_, _, x, y| covariance(x, y).map(|(cov, _, _)| cov))
+}
@@ -0,0 +1,290 @@
+//! Typed tabular data.
+//!
+//! A `DataFrame` holds named Series which were parsed once from a RawFrame. Each column has a `DataType` and the list of
+//! names and types is the schema of the DataFrame. A DataFrame can be converted back to a RawFrame to use the column
+//! accessors of the RawFrame or to write it with the writing module.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::dataframe::{DataFrame, DataType};
+//! use std::ffi::OsString;
+//!
+//! let path = OsString::from("./datos_test/test.csv");
+//! let datos = RawFrame::from_os_string(path).unwrap();
+//!
+//! let frame = datos.to_dataframe(&[("col_a", DataType::Float), ("col_b", DataType::Int)]).unwrap();
+//!
+//! assert_eq!(frame.nrows(), 5);
+//! assert_eq!(frame.float("col_a").unwrap().iter_valid().sum::<f64>(), 69.5);
+//! assert_eq!(frame.int("col_b").unwrap().null_count(), 3);
+//!
+//! let raw = frame.to_raw();
+//! assert_eq!(raw.records[1].get(1), Some(""));
+//! ```
+
+use crate::RavenError;
+use crate::dates::Date;
+use crate::series::Series;
+use std::fmt;
+
+/// Type of the values of a column in a DataFrame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum DataType {
+    Int,
+    Float,
+    Bool,
+    Date,
+    Str,
+}
+
+impl fmt::Display for DataType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let nombre = match self {
+            DataType::Int => "int",
+            DataType::Float => "float",
+            DataType::Bool => "bool",
+            DataType::Date => "date",
+            DataType::Str => "string",
+        };
+        write!(f, "{}", nombre)
+    }
+}
+
+/// A typed column of a DataFrame.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Column {
+    Int(Series<i64>),
+    Float(Series<f64>),
+    Bool(Series<bool>),
+    Date(Series<Date>),
+    Str(Series<String>),
+}
+
+impl Column {
+    /// Parses the cells of a column once into a Series of the given type.
+    pub fn parse<'a, I>(name: &str, data_type: DataType, cells: I) -> Column
+    where I: Iterator<Item=Option<&'a str>>
+    {
+        match data_type {
+            DataType::Int => Column::Int(Series::parse(name, cells)),
+            DataType::Float => Column::Float(Series::parse(name, cells)),
+            DataType::Bool => Column::Bool(Series::parse(name, cells)),
+            DataType::Date => Column::Date(Series::parse(name, cells)),
+            DataType::Str => Column::Str(Series::parse(name, cells)),
+        }
+    }
+
+    /// Returns the name of the column.
+    pub fn name(&self) -> &str {
+        match self {
+            Column::Int(serie) => serie.name(),
+            Column::Float(serie) => serie.name(),
+            Column::Bool(serie) => serie.name(),
+            Column::Date(serie) => serie.name(),
+            Column::Str(serie) => serie.name(),
+        }
+    }
+
+    /// Returns the type of the column.
+    pub fn data_type(&self) -> DataType {
+        match self {
+            Column::Int(_) => DataType::Int,
+            Column::Float(_) => DataType::Float,
+            Column::Bool(_) => DataType::Bool,
+            Column::Date(_) => DataType::Date,
+            Column::Str(_) => DataType::Str,
+        }
+    }
+
+    /// Returns the number of rows of the column.
+    pub fn len(&self) -> usize {
+        match self {
+            Column::Int(serie) => serie.len(),
+            Column::Float(serie) => serie.len(),
+            Column::Bool(serie) => serie.len(),
+            Column::Date(serie) => serie.len(),
+            Column::Str(serie) => serie.len(),
+        }
+    }
+
+    /// Returns true if the column has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the number of invalid rows of the column.
+    pub fn null_count(&self) -> usize {
+        match self {
+            Column::Int(serie) => serie.null_count(),
+            Column::Float(serie) => serie.null_count(),
+            Column::Bool(serie) => serie.null_count(),
+            Column::Date(serie) => serie.null_count(),
+            Column::Str(serie) => serie.null_count(),
+        }
+    }
+
+    /// Returns the value in position n formatted as a String, or None if it is invalid.
+    pub fn get_string(&self, n: usize) -> Option<String> {
+        match self {
+            Column::Int(serie) => serie.get(n).map(|val| val.to_string()),
+            Column::Float(serie) => serie.get(n).map(|val| val.to_string()),
+            Column::Bool(serie) => serie.get(n).map(|val| val.to_string()),
+            Column::Date(serie) => serie.get(n).map(|val| val.to_string()),
+            Column::Str(serie) => serie.get(n).cloned(),
+        }
+    }
+}
+
+/// Tabular data structure with typed columns.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DataFrame {
+    columns: Vec<Column>,
+}
+
+impl DataFrame {
+    /// Creates an empty DataFrame.
+    pub fn new() -> DataFrame {
+        DataFrame::default()
+    }
+
+    /// Creates a DataFrame parsing once the given columns of a RawFrame with the given types.
+    ///
+    /// # Arguments
+    ///
+    /// * `raw` - The RawFrame with the data
+    /// * `schema` - A slice of tuples with the name of each column and its DataType
+    pub fn from_raw(raw: &crate::RawFrame, schema: &[(&str, DataType)]) -> Result<DataFrame, RavenError> {
+        let nombres: Vec<&str> = schema.iter().map(|(nombre, _)| *nombre).collect();
+        let positions = raw.col_positions(&nombres)?;
+
+        let columns = schema.iter().zip(positions).map(|((nombre, data_type), position)| {
+            Column::parse(nombre, *data_type, raw.records.iter().map(|record| record.get(position)))
+        }).collect();
+
+        Ok(DataFrame { columns })
+    }
+
+    /// Adds a column at the end of the DataFrame. It returns an error if its length is different to the number of rows of the DataFrame.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - The Column to add
+    pub fn with_column(&mut self, column: Column) -> Result<(), RavenError> {
+        if !self.columns.is_empty() && column.len() != self.nrows() {
+            return Err(RavenError::LengthMismatch { expected: self.nrows(), found: column.len() })
+        }
+
+        match self.col_index(column.name()) {
+            Some(n) => self.columns[n] = column,
+            None => self.columns.push(column),
+        }
+
+        Ok(())
+    }
+
+    /// Returns the number of rows.
+    pub fn nrows(&self) -> usize {
+        self.columns.first().map_or(0, |column| column.len())
+    }
+
+    /// Returns the number of columns.
+    pub fn ncols(&self) -> usize {
+        self.columns.len()
+    }
+
+    /// Returns the names of the columns.
+    pub fn names(&self) -> Vec<&str> {
+        self.columns.iter().map(|column| column.name()).collect()
+    }
+
+    /// Returns the schema of the DataFrame, the name and the type of each column.
+    pub fn schema(&self) -> Vec<(&str, DataType)> {
+        self.columns.iter().map(|column| (column.name(), column.data_type())).collect()
+    }
+
+    /// Returns the position index for column in DataFrame or None if column does not exists.
+    pub fn col_index(&self, column: &str) -> Option<usize> {
+        self.columns.iter().position(|col| col.name() == column)
+    }
+
+    /// Returns all the columns.
+    pub fn columns(&self) -> &[Column] {
+        &self.columns
+    }
+
+    /// Returns a column or Error if column does not exists.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    pub fn column(&self, column: &str) -> Result<&Column, RavenError> {
+        match self.col_index(column) {
+            Some(n) => Ok(&self.columns[n]),
+            None => Err(RavenError::ColumnNotFound {
+                name: String::from(column),
+                available: self.names().into_iter().map(String::from).collect(),
+            })
+        }
+    }
+
+    /// Returns the Series of an int column or Error if column does not exists or has another type.
+    pub fn int(&self, column: &str) -> Result<&Series<i64>, RavenError> {
+        match self.column(column)? {
+            Column::Int(serie) => Ok(serie),
+            otra => Err(type_mismatch(column, DataType::Int, otra.data_type())),
+        }
+    }
+
+    /// Returns the Series of a float column or Error if column does not exists or has another type.
+    pub fn float(&self, column: &str) -> Result<&Series<f64>, RavenError> {
+        match self.column(column)? {
+            Column::Float(serie) => Ok(serie),
+            otra => Err(type_mismatch(column, DataType::Float, otra.data_type())),
+        }
+    }
+
+    /// Returns the Series of a bool column or Error if column does not exists or has another type.
+    pub fn boolean(&self, column: &str) -> Result<&Series<bool>, RavenError> {
+        match self.column(column)? {
+            Column::Bool(serie) => Ok(serie),
+            otra => Err(type_mismatch(column, DataType::Bool, otra.data_type())),
+        }
+    }
+
+    /// Returns the Series of a date column or Error if column does not exists or has another type.
+    pub fn date(&self, column: &str) -> Result<&Series<Date>, RavenError> {
+        match self.column(column)? {
+            Column::Date(serie) => Ok(serie),
+            otra => Err(type_mismatch(column, DataType::Date, otra.data_type())),
+        }
+    }
+
+    /// Returns the Series of a string column or Error if column does not exists or has another type.
+    pub fn string(&self, column: &str) -> Result<&Series<String>, RavenError> {
+        match self.column(column)? {
+            Column::Str(serie) => Ok(serie),
+            otra => Err(type_mismatch(column, DataType::Str, otra.data_type())),
+        }
+    }
+
+    /// Converts the DataFrame to a RawFrame. The values are formatted as strings and the invalid values are empty fields.
+    pub fn to_raw(&self) -> crate::RawFrame {
+        let columns: csv::StringRecord = self.names().into_iter().collect();
+
+        let records = (0..self.nrows()).map(|n| {
+            self.columns.iter().map(|column| column.get_string(n).unwrap_or_default()).collect::<csv::StringRecord>()
+        }).collect();
+
+        crate::RawFrame { columns, records }
+    }
+}
+
+fn type_mismatch(column: &str, expected: DataType, found: DataType) -> RavenError {
+    RavenError::TypeMismatch {
+        column: String::from(column),
+        expected: expected.to_string(),
+        found: found.to_string(),
+    }
+}
@@ -0,0 +1,704 @@
+//! Calendar dates and date times.
+//!
+//! `Date` is a day of the proleptic Gregorian calendar. It is parsed from ISO 8601 strings like `2020-03-15`.
+//! `DateTime` is a date with a time of the day and an optional offset from UTC. It is parsed from ISO 8601 strings
+//! like `2020-03-15T10:30:00`, `2020-03-15 10:30:00.250` or `2020-03-15T10:30:00-06:00`.
+//!
+//! Other formats are read with a `DateFormat`, a strftime-style format like `%d/%m/%Y %H:%M`. When the format of a column
+//! is unknown `DateFormat::detect` tries a list of common formats and keeps the one which reads more values.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::dates::Date;
+//!
+//! let fecha: Date = "2020-03-15".parse().unwrap();
+//!
+//! assert_eq!(fecha.year(), 2020);
+//! assert_eq!(fecha.month(), 3);
+//! assert_eq!(fecha.day(), 15);
+//! assert_eq!(fecha.to_string(), "2020-03-15");
+//! assert!("2020-02-30".parse::<Date>().is_err());
+//! ```
+
+use std::fmt;
+
+/// A day of the proleptic Gregorian calendar.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Default for Date {
+    fn default() -> Date {
+        Date { year: 1970, month: 1, day: 1 }
+    }
+}
+
+/// Error returned when a string is not a valid date.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDateError;
+
+impl fmt::Display for ParseDateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid date")
+    }
+}
+
+impl std::error::Error for ParseDateError {}
+
+/// Returns true if the year is a leap year.
+pub fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Returns the number of days of a month, or 0 if the month is not between 1 and 12.
+pub fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+impl Date {
+    /// Creates a Date or returns None if the day does not exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The year
+    /// * `month` - The month, from 1 to 12
+    /// * `day` - The day of the month, starting at 1
+    pub fn new(year: i32, month: u32, day: u32) -> Option<Date> {
+        if day == 0 || day > days_in_month(year, month) {
+            return None
+        }
+        Some(Date { year, month, day })
+    }
+
+    /// Returns the year.
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month, from 1 to 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month, starting at 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the number of days since 1970-01-01, negative for previous dates.
+    pub fn days_since_epoch(&self) -> i64 {
+        let year = i64::from(self.year) - if self.month <= 2 { 1 } else { 0 };
+        let era = year.div_euclid(400);
+        let yoe = year - era * 400;
+        let month = i64::from(self.month);
+        let doy = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + i64::from(self.day) - 1;
+        let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+
+        era * 146097 + doe - 719468
+    }
+
+    /// Returns the day of the week, from 0 for Monday to 6 for Sunday.
+    pub fn weekday(&self) -> u32 {
+        (self.days_since_epoch() + 3).rem_euclid(7) as u32
+    }
+
+    /// Creates a Date from the number of days since 1970-01-01.
+    pub fn from_days_since_epoch(days: i64) -> Date {
+        let z = days + 719468;
+        let era = z.div_euclid(146097);
+        let doe = z - era * 146097;
+        let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+        let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+        let mp = (5 * doy + 2) / 153;
+        let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+        let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+        let year = (yoe + era * 400 + if month <= 2 { 1 } else { 0 }) as i32;
+
+        Date { year, month, day }
+    }
+}
+
+impl std::str::FromStr for Date {
+    type Err = ParseDateError;
+
+    /// Parses a date in the ISO 8601 format `YYYY-MM-DD`.
+    fn from_str(cadena: &str) -> Result<Date, ParseDateError> {
+        let partes: Vec<&str> = cadena.split('-').collect();
+
+        if partes.len() != 3 || partes[0].len() != 4 || partes[1].len() != 2 || partes[2].len() != 2 {
+            return Err(ParseDateError)
+        }
+
+        if !partes.iter().all(|parte| parte.bytes().all(|b| b.is_ascii_digit())) {
+            return Err(ParseDateError)
+        }
+
+        let year = partes[0].parse::<i32>().map_err(|_| ParseDateError)?;
+        let month = partes[1].parse::<u32>().map_err(|_| ParseDateError)?;
+        let day = partes[2].parse::<u32>().map_err(|_| ParseDateError)?;
+
+        Date::new(year, month, day).ok_or(ParseDateError)
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A date with a time of the day and an optional offset from UTC.
+///
+/// Date times are compared by the instant they represent, a date time without offset is considered to be in UTC.
+///
+/// # Examples
+///
+/// ```
+/// use ravencol::dates::DateTime;
+///
+/// let local: DateTime = "2020-03-15T10:30:00-06:00".parse().unwrap();
+/// let utc: DateTime = "2020-03-15 16:30:00Z".parse().unwrap();
+///
+/// assert_eq!(local, utc);
+/// assert_eq!(local.hour(), 10);
+/// assert_eq!(local.offset(), Some(-21600));
+/// assert_eq!(local.to_string(), "2020-03-15T10:30:00-06:00");
+/// ```
+#[derive(Debug, Clone, Copy, Default)]
+pub struct DateTime {
+    date: Date,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+    offset: Option<i32>,
+}
+
+impl DateTime {
+    /// Creates a DateTime or returns None if the time is not valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The Date
+    /// * `hour` - The hour, from 0 to 23
+    /// * `minute` - The minute, from 0 to 59
+    /// * `second` - The second, from 0 to 59
+    /// * `nanosecond` - The fraction of second in nanoseconds
+    /// * `offset` - The offset from UTC in seconds, None if it is unknown
+    pub fn new(date: Date, hour: u32, minute: u32, second: u32, nanosecond: u32, offset: Option<i32>) -> Option<DateTime> {
+        if hour > 23 || minute > 59 || second > 59 || nanosecond >= 1_000_000_000 {
+            return None
+        }
+        if offset.map_or(false, |offset| !(-86399..=86399).contains(&offset)) {
+            return None
+        }
+        Some(DateTime { date, hour, minute, second, nanosecond, offset })
+    }
+
+    /// Returns the date.
+    pub fn date(&self) -> Date {
+        self.date
+    }
+
+    /// Returns the hour, from 0 to 23.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute, from 0 to 59.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+
+    /// Returns the second, from 0 to 59.
+    pub fn second(&self) -> u32 {
+        self.second
+    }
+
+    /// Returns the fraction of second in nanoseconds.
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// Returns the offset from UTC in seconds, None if it is unknown.
+    pub fn offset(&self) -> Option<i32> {
+        self.offset
+    }
+
+    /// Returns the number of seconds since 1970-01-01T00:00:00 UTC. A date time without offset is considered to be in UTC.
+    pub fn timestamp(&self) -> i64 {
+        self.date.days_since_epoch() * 86400
+            + i64::from(self.hour * 3600 + self.minute * 60 + self.second)
+            - i64::from(self.offset.unwrap_or(0))
+    }
+
+    /// Creates the DateTime of an instant shown with an offset from UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `timestamp` - The number of seconds since 1970-01-01T00:00:00 UTC
+    /// * `nanosecond` - The fraction of second in nanoseconds
+    /// * `offset` - The offset from UTC in seconds of the result, None means UTC without an explicit offset
+    pub fn from_timestamp(timestamp: i64, nanosecond: u32, offset: Option<i32>) -> Option<DateTime> {
+        let local = timestamp + i64::from(offset.unwrap_or(0));
+        let date = Date::from_days_since_epoch(local.div_euclid(86400));
+        let segundos = local.rem_euclid(86400) as u32;
+
+        DateTime::new(date, segundos / 3600, (segundos % 3600) / 60, segundos % 60, nanosecond, offset)
+    }
+
+    /// Returns the same instant shown with another offset from UTC.
+    ///
+    /// # Arguments
+    ///
+    /// * `offset` - The offset from UTC in seconds
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::dates::DateTime;
+    ///
+    /// let local: DateTime = "2020-03-15T22:30:00-06:00".parse().unwrap();
+    ///
+    /// assert_eq!(local.to_utc().to_string(), "2020-03-16T04:30:00Z");
+    /// assert_eq!(local.to_offset(3600).unwrap().to_string(), "2020-03-16T05:30:00+01:00");
+    /// ```
+    pub fn to_offset(&self, offset: i32) -> Option<DateTime> {
+        DateTime::from_timestamp(self.timestamp(), self.nanosecond, Some(offset))
+    }
+
+    /// Returns the same instant shown in UTC.
+    pub fn to_utc(&self) -> DateTime {
+        let mut utc = DateTime::from_timestamp(self.timestamp(), self.nanosecond, None).unwrap_or_default();
+        utc.offset = Some(0);
+        utc
+    }
+}
+
+impl PartialEq for DateTime {
+    fn eq(&self, other: &DateTime) -> bool {
+        self.cmp(other) == std::cmp::Ordering::Equal
+    }
+}
+
+impl Eq for DateTime {}
+
+impl PartialOrd for DateTime {
+    fn partial_cmp(&self, other: &DateTime) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for DateTime {
+    fn cmp(&self, other: &DateTime) -> std::cmp::Ordering {
+        (self.timestamp(), self.nanosecond).cmp(&(other.timestamp(), other.nanosecond))
+    }
+}
+
+/// Parses an offset like `Z`, `+05:30`, `-0600` or `-06` into seconds.
+fn parse_offset(cadena: &str) -> Option<i32> {
+    if cadena == "Z" || cadena == "z" {
+        return Some(0)
+    }
+
+    let signo = match cadena.chars().next()? {
+        '+' => 1,
+        '-' => -1,
+        _ => return None,
+    };
+
+    let digitos: String = cadena[1..].chars().filter(|c| *c != ':').collect();
+    if !digitos.bytes().all(|b| b.is_ascii_digit()) || (digitos.len() != 2 && digitos.len() != 4) {
+        return None
+    }
+
+    let horas: i32 = digitos[..2].parse().ok()?;
+    let minutos: i32 = if digitos.len() == 4 { digitos[2..].parse().ok()? } else { 0 };
+
+    if horas > 23 || minutos > 59 {
+        return None
+    }
+
+    Some(signo * (horas * 3600 + minutos * 60))
+}
+
+impl std::str::FromStr for DateTime {
+    type Err = ParseDateError;
+
+    /// Parses a date time in the ISO 8601 format `YYYY-MM-DDTHH:MM[:SS[.fff]][offset]`. The separator can be `T` or a space.
+    fn from_str(cadena: &str) -> Result<DateTime, ParseDateError> {
+        if cadena.len() < 16 || !cadena.is_char_boundary(10) {
+            return Err(ParseDateError)
+        }
+
+        let date: Date = cadena[..10].parse()?;
+
+        let resto = cadena[10..].strip_prefix(['T', ' ']).ok_or(ParseDateError)?;
+
+        let fin = resto.find(['Z', 'z', '+', '-']).unwrap_or(resto.len());
+        let (tiempo, offset) = resto.split_at(fin);
+        let offset = if offset.is_empty() { None } else { Some(parse_offset(offset).ok_or(ParseDateError)?) };
+
+        let (tiempo, fraccion) = match tiempo.find('.') {
+            Some(n) => (&tiempo[..n], &tiempo[n + 1..]),
+            None => (tiempo, ""),
+        };
+
+        let partes: Vec<&str> = tiempo.split(':').collect();
+        if partes.len() < 2 || partes.len() > 3 || partes.iter().any(|parte| parte.len() != 2 || !parte.bytes().all(|b| b.is_ascii_digit())) {
+            return Err(ParseDateError)
+        }
+        if partes.len() == 2 && !fraccion.is_empty() {
+            return Err(ParseDateError)
+        }
+
+        let hour = partes[0].parse().map_err(|_| ParseDateError)?;
+        let minute = partes[1].parse().map_err(|_| ParseDateError)?;
+        let second = if partes.len() == 3 { partes[2].parse().map_err(|_| ParseDateError)? } else { 0 };
+
+        if fraccion.len() > 9 || !fraccion.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ParseDateError)
+        }
+        let nanosecond = if fraccion.is_empty() {
+            0
+        } else {
+            format!("{:0<9}", fraccion).parse().map_err(|_| ParseDateError)?
+        };
+
+        DateTime::new(date, hour, minute, second, nanosecond, offset).ok_or(ParseDateError)
+    }
+}
+
+impl fmt::Display for DateTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}T{:02}:{:02}:{:02}", self.date, self.hour, self.minute, self.second)?;
+
+        if self.nanosecond > 0 {
+            let fraccion = format!("{:09}", self.nanosecond);
+            write!(f, ".{}", fraccion.trim_end_matches('0'))?;
+        }
+
+        match self.offset {
+            None => Ok(()),
+            Some(0) => write!(f, "Z"),
+            Some(offset) => {
+                let signo = if offset < 0 { '-' } else { '+' };
+                let offset = offset.abs();
+                write!(f, "{}{:02}:{:02}", signo, offset / 3600, (offset % 3600) / 60)
+            }
+        }
+    }
+}
+
+/// Month names accepted by `%b` and `%B`, in English and Spanish. The full names are checked before the abbreviations.
+const MESES: [(&str, u32); 39] = [
+    ("january", 1), ("february", 2), ("march", 3), ("april", 4), ("june", 6), ("july", 7), ("august", 8),
+    ("september", 9), ("october", 10), ("november", 11), ("december", 12),
+    ("enero", 1), ("febrero", 2), ("marzo", 3), ("abril", 4), ("mayo", 5), ("junio", 6), ("julio", 7), ("agosto", 8),
+    ("septiembre", 9), ("octubre", 10), ("noviembre", 11), ("diciembre", 12),
+    ("jan", 1), ("ene", 1), ("feb", 2), ("mar", 3), ("apr", 4), ("abr", 4), ("may", 5), ("jun", 6), ("jul", 7),
+    ("aug", 8), ("ago", 8), ("sep", 9), ("oct", 10), ("nov", 11), ("dec", 12), ("dic", 12),
+];
+
+/// Date formats tried by `DateFormat::detect`, in order of preference.
+pub const DATE_FORMATS: [&str; 9] = [
+    "%Y-%m-%d", "%Y/%m/%d", "%d/%m/%Y", "%m/%d/%Y", "%d-%m-%Y", "%d.%m.%Y", "%Y%m%d", "%d %b %Y", "%b %d, %Y",
+];
+
+/// Date time formats tried by `DateFormat::detect`, in order of preference.
+pub const DATETIME_FORMATS: [&str; 12] = [
+    "%Y-%m-%dT%H:%M:%S%z", "%Y-%m-%dT%H:%M:%S", "%Y-%m-%dT%H:%M:%S.%f%z", "%Y-%m-%dT%H:%M:%S.%f",
+    "%Y-%m-%d %H:%M:%S%z", "%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%d/%m/%Y %H:%M:%S", "%d/%m/%Y %H:%M",
+    "%m/%d/%Y %H:%M:%S", "%m/%d/%Y %H:%M", "%m/%d/%Y %I:%M %p",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Item {
+    Literal(char),
+    Year,
+    ShortYear,
+    Month,
+    MonthName,
+    Day,
+    PaddedDay,
+    Hour,
+    Hour12,
+    AmPm,
+    Minute,
+    Second,
+    Fraction,
+    Offset,
+}
+
+/// The values read from a string with a DateFormat.
+#[derive(Debug, Default)]
+struct Partes {
+    year: Option<i32>,
+    month: Option<u32>,
+    day: Option<u32>,
+    hour: u32,
+    pm: Option<bool>,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+    offset: Option<i32>,
+}
+
+/// A strftime-style format to read dates and date times.
+///
+/// The supported specifiers are `%Y` (four digit year), `%y` (two digit year, 1969 to 2068), `%m` (month number),
+/// `%b`, `%B` or `%h` (month name in English or Spanish), `%d` (day), `%e` (day padded with a space), `%H` (hour from 0 to 23),
+/// `%I` (hour from 1 to 12), `%p` (AM or PM), `%M` (minute), `%S` (second), `%f` (fraction of second), `%z` (offset from
+/// UTC like `Z`, `-06:00` or `+0530`), `%F` (same as `%Y-%m-%d`), `%T` (same as `%H:%M:%S`) and `%%` (a literal `%`).
+/// Any other character must appear in the string.
+///
+/// # Examples
+///
+/// ```
+/// use ravencol::dates::{Date, DateFormat, DateTime};
+///
+/// let formato = DateFormat::new("%d/%m/%Y %H:%M %z").unwrap();
+/// let fecha: DateTime = formato.parse_datetime("15/03/2020 10:30 -06:00").unwrap();
+///
+/// assert_eq!(fecha.to_string(), "2020-03-15T10:30:00-06:00");
+///
+/// let formato = DateFormat::new("%d de %B de %Y").unwrap();
+/// assert_eq!(formato.parse_date("5 de marzo de 2020"), Date::new(2020, 3, 5));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DateFormat {
+    format: String,
+    items: Vec<Item>,
+}
+
+impl DateFormat {
+    /// Creates a DateFormat or returns an error if the format has an unknown specifier.
+    ///
+    /// # Arguments
+    ///
+    /// * `format` - A string slice with the strftime-style format
+    pub fn new(format: &str) -> Result<DateFormat, crate::RavenError> {
+        let mut items = Vec::new();
+        let mut chars = format.chars();
+
+        while let Some(c) = chars.next() {
+            if c != '%' {
+                items.push(Item::Literal(c));
+                continue;
+            }
+
+            let especificador = chars.next();
+            match especificador {
+                Some('Y') => items.push(Item::Year),
+                Some('y') => items.push(Item::ShortYear),
+                Some('m') => items.push(Item::Month),
+                Some('b') | Some('B') | Some('h') => items.push(Item::MonthName),
+                Some('d') => items.push(Item::Day),
+                Some('e') => items.push(Item::PaddedDay),
+                Some('H') => items.push(Item::Hour),
+                Some('I') => items.push(Item::Hour12),
+                Some('p') => items.push(Item::AmPm),
+                Some('M') => items.push(Item::Minute),
+                Some('S') => items.push(Item::Second),
+                Some('f') => items.push(Item::Fraction),
+                Some('z') => items.push(Item::Offset),
+                Some('F') => items.extend([Item::Year, Item::Literal('-'), Item::Month, Item::Literal('-'), Item::Day]),
+                Some('T') => items.extend([Item::Hour, Item::Literal(':'), Item::Minute, Item::Literal(':'), Item::Second]),
+                Some('%') => items.push(Item::Literal('%')),
+                otro => {
+                    let error = match otro {
+                        Some(c) => format!("unknown specifier %{}", c),
+                        None => String::from("incomplete specifier at the end"),
+                    };
+                    return Err(crate::RavenError::InvalidDateFormat { format: String::from(format), error })
+                },
+            }
+        }
+
+        Ok(DateFormat { format: String::from(format), items })
+    }
+
+    /// Returns the format as a string slice.
+    pub fn as_str(&self) -> &str {
+        &self.format
+    }
+
+    /// Returns the first format of the candidates which reads the most values, or None if no format reads any value.
+    /// Empty strings are not taken into account.
+    ///
+    /// # Arguments
+    ///
+    /// * `cells` - An iterator with the values to read
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::dates::{Date, DateFormat};
+    ///
+    /// let formato = DateFormat::detect::<Date, _>(vec!["15/03/2020", "28/02/2020"].into_iter()).unwrap();
+    ///
+    /// assert_eq!(formato.as_str(), "%d/%m/%Y");
+    /// ```
+    pub fn detect<'a, X, I>(cells: I) -> Option<DateFormat>
+    where X: Temporal, I: Iterator<Item=&'a str>
+    {
+        let candidatos: Vec<DateFormat> = X::formats().iter().filter_map(|format| DateFormat::new(format).ok()).collect();
+        let mut conteos = vec![0usize; candidatos.len()];
+
+        for cadena in cells.filter(|cadena| !cadena.is_empty()) {
+            for (formato, conteo) in candidatos.iter().zip(conteos.iter_mut()) {
+                if X::parse_with(cadena, formato).is_some() {
+                    *conteo += 1;
+                }
+            }
+        }
+
+        let maximo = conteos.iter().copied().max().filter(|maximo| *maximo > 0)?;
+        let n = conteos.iter().position(|conteo| *conteo == maximo)?;
+
+        candidatos.into_iter().nth(n)
+    }
+
+    /// Reads a Date from a string. The time of the day is read but it is not used.
+    pub fn parse_date(&self, cadena: &str) -> Option<Date> {
+        let partes = self.read(cadena)?;
+
+        Date::new(partes.year?, partes.month?, partes.day?)
+    }
+
+    /// Reads a DateTime from a string. If the format has no time the result is at midnight.
+    pub fn parse_datetime(&self, cadena: &str) -> Option<DateTime> {
+        let partes = self.read(cadena)?;
+        let date = Date::new(partes.year?, partes.month?, partes.day?)?;
+
+        let hour = match partes.pm {
+            None => partes.hour,
+            Some(_) if partes.hour == 0 || partes.hour > 12 => return None,
+            Some(pm) => partes.hour % 12 + if pm { 12 } else { 0 },
+        };
+
+        DateTime::new(date, hour, partes.minute, partes.second, partes.nanosecond, partes.offset)
+    }
+
+    fn read(&self, cadena: &str) -> Option<Partes> {
+        let mut partes = Partes::default();
+        let mut resto = cadena;
+
+        for item in &self.items {
+            match item {
+                Item::Literal(c) => resto = resto.strip_prefix(*c)?,
+                Item::Year => partes.year = Some(digits(&mut resto, 4, 4)? as i32),
+                Item::ShortYear => {
+                    let year = digits(&mut resto, 2, 2)? as i32;
+                    partes.year = Some(if year < 69 { 2000 + year } else { 1900 + year });
+                },
+                Item::Month => partes.month = Some(digits(&mut resto, 1, 2)?),
+                Item::MonthName => partes.month = Some(month_name(&mut resto)?),
+                Item::Day => partes.day = Some(digits(&mut resto, 1, 2)?),
+                Item::PaddedDay => {
+                    resto = resto.strip_prefix(' ').unwrap_or(resto);
+                    partes.day = Some(digits(&mut resto, 1, 2)?);
+                },
+                Item::Hour | Item::Hour12 => partes.hour = digits(&mut resto, 1, 2)?,
+                Item::AmPm => {
+                    let marca = resto.get(..2)?.to_ascii_uppercase();
+                    partes.pm = match marca.as_str() {
+                        "AM" => Some(false),
+                        "PM" => Some(true),
+                        _ => return None,
+                    };
+                    resto = &resto[2..];
+                },
+                Item::Minute => partes.minute = digits(&mut resto, 1, 2)?,
+                Item::Second => partes.second = digits(&mut resto, 1, 2)?,
+                Item::Fraction => {
+                    let ancho = resto.bytes().take_while(|b| b.is_ascii_digit()).count().min(9);
+                    let nanos = digits(&mut resto, 1, ancho)?;
+                    partes.nanosecond = nanos * 10u32.pow(9 - ancho as u32);
+                },
+                Item::Offset => {
+                    let ancho = match resto.chars().next()? {
+                        'Z' | 'z' => 1,
+                        '+' | '-' => 1 + resto[1..].bytes().take_while(|b| b.is_ascii_digit() || *b == b':').count().min(5),
+                        _ => return None,
+                    };
+                    partes.offset = Some(parse_offset(&resto[..ancho])?);
+                    resto = &resto[ancho..];
+                },
+            }
+        }
+
+        if resto.is_empty() {
+            Some(partes)
+        } else {
+            None
+        }
+    }
+}
+
+impl fmt::Display for DateFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.format)
+    }
+}
+
+/// Reads from the start of the string between min and max digits and advances the string.
+fn digits(resto: &mut &str, min: usize, max: usize) -> Option<u32> {
+    let ancho = resto.bytes().take(max).take_while(|b| b.is_ascii_digit()).count();
+    if ancho < min {
+        return None
+    }
+
+    let valor = resto[..ancho].parse().ok()?;
+    *resto = &resto[ancho..];
+    Some(valor)
+}
+
+/// Reads a month name from the start of the string and advances the string.
+fn month_name(resto: &mut &str) -> Option<u32> {
+    let minusculas = resto.to_lowercase();
+
+    let (nombre, mes) = MESES.iter().find(|(nombre, _)| minusculas.starts_with(nombre))?;
+
+    *resto = resto.get(nombre.len()..)?;
+    Some(*mes)
+}
+
+/// Date types which can be read from the columns of a RawFrame with a DateFormat. It is implemented for `Date` and `DateTime`.
+pub trait Temporal: Ord + Copy + 'static {
+    /// Reads a value from a string with a DateFormat.
+    fn parse_with(cadena: &str, format: &DateFormat) -> Option<Self>;
+
+    /// Returns the formats tried by `DateFormat::detect` for this type.
+    fn formats() -> Vec<&'static str>;
+}
+
+impl Temporal for Date {
+    fn parse_with(cadena: &str, format: &DateFormat) -> Option<Date> {
+        format.parse_date(cadena)
+    }
+
+    fn formats() -> Vec<&'static str> {
+        DATE_FORMATS.to_vec()
+    }
+}
+
+impl Temporal for DateTime {
+    fn parse_with(cadena: &str, format: &DateFormat) -> Option<DateTime> {
+        format.parse_datetime(cadena)
+    }
+
+    fn formats() -> Vec<&'static str> {
+        DATETIME_FORMATS.iter().chain(DATE_FORMATS.iter()).copied().collect()
+    }
+}
@@ -0,0 +1,241 @@
+//! Summary statistics of every column.
+//!
+//! `RawFrame::describe` inspects all the cells of each column and returns a `Summary`: the inferred type, the number of
+//! non null values, null values and values which can not be represented with the type, the number of distinct values and
+//! the most frequent values. For integer and float columns it also has the mean, the sample standard deviation, the
+//! minimum, the quartiles and the maximum, computed with the values which can be parsed as f64.
+//!
+//! A Summary prints as a table and can be converted to a RawFrame or written to a CSV file.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::dataframe::DataType;
+//!
+//! let datos: RawFrame = "region,ventas\nsur,10\nnorte,20\nsur,NA\nsur,30\n,40\n".parse().unwrap();
+//!
+//! let resumen = datos.describe();
+//! println!("{}", resumen);
+//!
+//! let ventas = resumen.get("ventas").unwrap();
+//! assert_eq!(ventas.data_type, DataType::Int);
+//! assert_eq!(ventas.invalid_count, 1);
+//! assert_eq!(ventas.mean, Some(25.0));
+//! assert_eq!(ventas.median, Some(25.0));
+//!
+//! let region = resumen.get("region").unwrap();
+//! assert_eq!(region.null_count, 1);
+//! assert_eq!(region.top[0], (String::from("sur"), 3));
+//! ```
+
+use crate::RavenError;
+use crate::dataframe::DataType;
+use crate::schema::{self, InferMode};
+use crate::stats::{self, QuantileMethod, Welford};
+use std::collections::HashMap;
+use std::ffi::OsString;
+use std::fmt;
+
+/// Summary statistics of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSummary {
+    /// Name of the column.
+    pub name: String,
+    /// Dominant type of the non null values, see `RawFrame::infer_schema`.
+    pub data_type: DataType,
+    /// Number of non null values.
+    pub count: usize,
+    /// Number of empty or missing fields.
+    pub null_count: usize,
+    /// Number of non null values which can not be represented with the type.
+    pub invalid_count: usize,
+    pub mean: Option<f64>,
+    /// Sample standard deviation, None with less than two values.
+    pub std: Option<f64>,
+    pub min: Option<f64>,
+    /// First quartile.
+    pub q1: Option<f64>,
+    pub median: Option<f64>,
+    /// Third quartile.
+    pub q3: Option<f64>,
+    pub max: Option<f64>,
+    /// Number of distinct non null values as written.
+    pub n_unique: usize,
+    /// The most frequent non null values with their frequency, ties keep the order of first appearance.
+    pub top: Vec<(String, usize)>,
+}
+
+/// Summary statistics of all the columns of a RawFrame.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Summary {
+    pub columns: Vec<ColumnSummary>,
+}
+
+const ENCABEZADOS: [&str; 14] = ["column", "type", "count", "null", "invalid", "mean", "std", "min", "25%", "50%", "75%", "max", "unique", "top"];
+
+impl Summary {
+    /// Returns the summary of a column or None if column does not exists.
+    pub fn get(&self, column: &str) -> Option<&ColumnSummary> {
+        self.columns.iter().find(|col| col.name == column)
+    }
+
+    /// Returns the fields of each column, the numbers are formatted with `format`.
+    fn rows<F>(&self, format: F) -> Vec<Vec<String>>
+    where F: Fn(f64) -> String
+    {
+        let numero = |valor: Option<f64>| valor.map_or(String::new(), &format);
+
+        self.columns.iter().map(|col| {
+            let top: Vec<String> = col.top.iter().map(|(valor, conteo)| format!("{} ({})", valor, conteo)).collect();
+            vec![
+                col.name.clone(),
+                col.data_type.to_string(),
+                col.count.to_string(),
+                col.null_count.to_string(),
+                col.invalid_count.to_string(),
+                numero(col.mean),
+                numero(col.std),
+                numero(col.min),
+                numero(col.q1),
+                numero(col.median),
+                numero(col.q3),
+                numero(col.max),
+                col.n_unique.to_string(),
+                top.join(", "),
+            ]
+        }).collect()
+    }
+
+    /// Returns a RawFrame with one record per column. The top values are written in one field like `sur (3), norte (1)`.
+    pub fn to_rawframe(&self) -> crate::RawFrame {
+        let columns = csv::StringRecord::from(ENCABEZADOS.to_vec());
+        let records = self.rows(|valor| valor.to_string()).into_iter().map(csv::StringRecord::from).collect();
+
+        crate::RawFrame { columns, records }
+    }
+
+    /// Writes the summary to a CSV file with one record per column.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - An OsString with the path of the file
+    pub fn to_csv(&self, path: OsString) -> Result<(), RavenError> {
+        crate::writing::to_csv_iter(path, ENCABEZADOS.to_vec(), self.rows(|valor| valor.to_string()).into_iter())
+    }
+}
+
+/// Formats a number for printing with at most four decimals.
+fn compacto(valor: f64) -> String {
+    if valor.fract() == 0.0 && valor.abs() < 1e15 {
+        format!("{}", valor)
+    } else {
+        let cadena = format!("{:.4}", valor);
+        String::from(cadena.trim_end_matches('0').trim_end_matches('.'))
+    }
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let filas = self.rows(compacto);
+
+        let anchos: Vec<usize> = ENCABEZADOS.iter().enumerate()
+            .map(|(n, encabezado)| filas.iter().map(|fila| fila[n].chars().count()).chain(Some(encabezado.len())).max().unwrap_or(0))
+            .collect();
+
+        let linea = |f: &mut fmt::Formatter<'_>, campos: &[&str]| -> fmt::Result {
+            let celdas: Vec<String> = campos.iter().zip(anchos.iter()).enumerate().map(|(n, (campo, ancho))| {
+                if n < 2 || n == campos.len() - 1 {
+                    format!("{:<ancho$}", campo, ancho = ancho)
+                } else {
+                    format!("{:>ancho$}", campo, ancho = ancho)
+                }
+            }).collect();
+            writeln!(f, "{}", celdas.join("  ").trim_end())
+        };
+
+        linea(f, &ENCABEZADOS)?;
+        for fila in &filas {
+            linea(f, &fila.iter().map(String::as_str).collect::<Vec<&str>>())?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the summary of a column from its cells. The cells which are None are null values.
+fn describe_column<'a, I>(name: &str, cells: I, top_k: usize) -> ColumnSummary
+where I: Iterator<Item=Option<&'a str>> + Clone
+{
+    let data_type = schema::infer_column(name, cells.clone(), InferMode::Strict).data_type;
+    let numerico = data_type == DataType::Int || data_type == DataType::Float;
+
+    let mut count = 0;
+    let mut null_count = 0;
+    let mut invalid_count = 0;
+    let mut valores = Vec::new();
+    let mut frecuencias: HashMap<&str, (usize, usize)> = HashMap::new();
+
+    for cell in cells {
+        let cadena = match cell {
+            Some(cadena) => cadena,
+            None => {
+                null_count += 1;
+                continue
+            },
+        };
+
+        count += 1;
+        if !schema::conforms(schema::classify(cadena), data_type) {
+            invalid_count += 1;
+        }
+        if numerico {
+            if let Ok(valor) = cadena.parse::<f64>() {
+                if !valor.is_nan() {
+                    valores.push(valor);
+                }
+            }
+        }
+
+        let orden = frecuencias.len();
+        frecuencias.entry(cadena).or_insert((0, orden)).0 += 1;
+    }
+
+    let mut welford = Welford::default();
+    valores.iter().for_each(|valor| welford.push(*valor));
+    valores.sort_by(|a, b| a.total_cmp(b));
+
+    let cuartil = |q: f64| stats::quantile_sorted(&valores, q, QuantileMethod::Linear);
+
+    let n_unique = frecuencias.len();
+    let mut top: Vec<(&str, (usize, usize))> = frecuencias.into_iter().collect();
+    top.sort_by(|a, b| (b.1).0.cmp(&(a.1).0).then((a.1).1.cmp(&(b.1).1)));
+    top.truncate(top_k);
+
+    ColumnSummary {
+        name: String::from(name),
+        data_type,
+        count,
+        null_count,
+        invalid_count,
+        mean: if welford.count > 0 { Some(welford.mean) } else { None },
+        std: welford.variance().map(f64::sqrt),
+        min: valores.first().copied(),
+        q1: cuartil(0.25),
+        median: cuartil(0.5),
+        q3: cuartil(0.75),
+        max: valores.last().copied(),
+        n_unique,
+        top: top.into_iter().map(|(valor, (conteo, _))| (String::from(valor), conteo)).collect(),
+    }
+}
+
+/// Returns the summary of all the columns of a RawFrame.
+pub(crate) fn describe(raw: &crate::RawFrame, top_k: usize) -> Summary {
+    let columns = raw.columns.iter().enumerate().map(|(position, nombre)| {
+        let cells = raw.records.iter().map(move |record| record.get(position).filter(|cadena| !cadena.is_empty()));
+        describe_column(nombre, cells, top_k)
+    }).collect();
+
+    Summary { columns }
+}
@@ -0,0 +1,254 @@
+//! Error type returned by the fallible functions of the crate.
+//!
+//! All the errors are variants of `RavenError`, in this way callers can match on the kind of failure.
+//! The messages are in English by default, the language used by `Display` can be changed for the whole process with `set_language`.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::RavenError;
+//! use std::ffi::OsString;
+//!
+//! let path = OsString::from("./datos_test/test.csv");
+//! let datos = RawFrame::from_os_string(path).unwrap();
+//!
+//! match datos.col_fil::<i32>("col_z") {
+//!     Err(RavenError::ColumnNotFound { name, available }) => {
+//!         assert_eq!(name, "col_z");
+//!         assert_eq!(available, vec!["col_a", "col_b"]);
+//!     },
+//!     _ => unreachable!(),
+//! };
+//! ```
+
+use std::error::Error;
+use std::fmt;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+/// Language used for the messages of the errors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Language {
+    English,
+    Spanish,
+}
+
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+/// Sets the language used by `Display` for all the errors of the process.
+///
+/// # Examples
+///
+/// ```
+/// use ravencol::errors::{self, Language, RavenError};
+///
+/// let error = RavenError::EmptyColumn { column: String::from("col_a") };
+///
+/// errors::set_language(Language::Spanish);
+/// assert_eq!(error.to_string(), "No se encontraron valores válidos en la columna col_a");
+///
+/// errors::set_language(Language::English);
+/// assert_eq!(error.to_string(), "no valid values found in column col_a");
+/// ```
+pub fn set_language(language: Language) {
+    let valor = match language {
+        Language::English => 0,
+        Language::Spanish => 1,
+    };
+    LANGUAGE.store(valor, Ordering::Relaxed);
+}
+
+/// Returns the language currently used for the messages of the errors.
+pub fn language() -> Language {
+    match LANGUAGE.load(Ordering::Relaxed) {
+        1 => Language::Spanish,
+        _ => Language::English,
+    }
+}
+
+/// Error type for all the fallible operations over RawFrames.
+#[derive(Debug)]
+pub enum RavenError {
+    /// A requested column does not exist. It holds the requested name and the names of the available columns.
+    ColumnNotFound { name: String, available: Vec<String> },
+    /// One or more of the columns requested together do not exist. It holds all the missing names and the names of the available columns.
+    ColumnsNotFound { names: Vec<String>, available: Vec<String> },
+    /// The number of values provided does not match the number of columns requested.
+    LengthMismatch { expected: usize, found: usize },
+    /// The columns of two frames are not compatible. It holds the expected and the found column names.
+    SchemaMismatch { expected: Vec<String>, found: Vec<String> },
+    /// A column name appears more than once where names must be unique.
+    DuplicateColumn { name: String },
+    /// Two RawFrames concatenated by name with `ConcatMode::Strict` have different columns.
+    ColumnSetMismatch { missing: Vec<String>, extra: Vec<String> },
+    /// No file matches a glob pattern or a directory has no CSV files.
+    NoFilesFound { pattern: String },
+    /// A column has no valid values for the requested operation.
+    EmptyColumn { column: String },
+    /// A quantile is outside of the range from 0 to 1.
+    InvalidQuantile { q: f64 },
+    /// A column has a different type than the requested one.
+    TypeMismatch { column: String, expected: String, found: String },
+    /// A regular expression of a declared schema is not valid.
+    InvalidPattern { pattern: String, error: String },
+    /// A date format has an unknown specifier.
+    InvalidDateFormat { format: String, error: String },
+    /// A value does not satisfy a rule of a declared schema validated with `ValidationMode::Abort`.
+    ValidationFailed { line: u64, column: String, value: String, rule: String },
+    /// A `NaN` value was found in a column using `NanPolicy::Error`.
+    NanFound { column: String },
+    /// The terminal argument in the position does not exist.
+    MissingArgument(usize),
+    /// A malformed row was found while loading with `BadRowPolicy::Fail`.
+    MalformedRow { line: u64, byte: u64, error: String },
+    /// An error from the file system or the reader.
+    Io(std::io::Error),
+    /// An error from the csv crate which is not an I/O error.
+    Csv(csv::Error),
+}
+
+impl RavenError {
+    /// Returns the message of the error in the given language.
+    pub fn localized(&self, language: Language) -> String {
+        match (self, language) {
+            (RavenError::ColumnNotFound { name, available }, Language::English) => {
+                format!("column {} does not exist, available columns: {}", name, available.join(", "))
+            },
+            (RavenError::ColumnNotFound { name, available }, Language::Spanish) => {
+                format!("No existe la columna {}, columnas disponibles: {}", name, available.join(", "))
+            },
+            (RavenError::ColumnsNotFound { names, available }, Language::English) => {
+                format!("columns {} do not exist, available columns: {}", names.join(", "), available.join(", "))
+            },
+            (RavenError::ColumnsNotFound { names, available }, Language::Spanish) => {
+                format!("No existen las columnas {}, columnas disponibles: {}", names.join(", "), available.join(", "))
+            },
+            (RavenError::LengthMismatch { expected, found }, Language::English) => {
+                format!("expected {} values, one for each column, but found {}", expected, found)
+            },
+            (RavenError::LengthMismatch { expected, found }, Language::Spanish) => {
+                format!("Se esperaban {} valores, uno por columna, y se encontraron {}", expected, found)
+            },
+            (RavenError::DuplicateColumn { name }, Language::English) => {
+                format!("the column {} appears more than once", name)
+            },
+            (RavenError::DuplicateColumn { name }, Language::Spanish) => {
+                format!("La columna {} aparece más de una vez", name)
+            },
+            (RavenError::ColumnSetMismatch { missing, extra }, Language::English) => {
+                format!("the columns are not the same, missing columns: ({}), extra columns: ({})", missing.join(", "), extra.join(", "))
+            },
+            (RavenError::ColumnSetMismatch { missing, extra }, Language::Spanish) => {
+                format!("Las columnas no son las mismas, columnas faltantes: ({}), columnas adicionales: ({})", missing.join(", "), extra.join(", "))
+            },
+            (RavenError::NoFilesFound { pattern }, Language::English) => {
+                format!("no files found for {}", pattern)
+            },
+            (RavenError::NoFilesFound { pattern }, Language::Spanish) => {
+                format!("No se encontraron archivos para {}", pattern)
+            },
+            (RavenError::SchemaMismatch { expected, found }, Language::English) => {
+                format!("the columns are not the same, expected {} columns ({}) but found {} columns ({})",
+                    expected.len(), expected.join(", "), found.len(), found.join(", "))
+            },
+            (RavenError::SchemaMismatch { expected, found }, Language::Spanish) => {
+                format!("Las columnas no son las mismas, se esperaban {} columnas ({}) y se encontraron {} columnas ({})",
+                    expected.len(), expected.join(", "), found.len(), found.join(", "))
+            },
+            (RavenError::EmptyColumn { column }, Language::English) => {
+                format!("no valid values found in column {}", column)
+            },
+            (RavenError::EmptyColumn { column }, Language::Spanish) => {
+                format!("No se encontraron valores válidos en la columna {}", column)
+            },
+            (RavenError::InvalidQuantile { q }, Language::English) => {
+                format!("invalid quantile {}, it must be between 0 and 1", q)
+            },
+            (RavenError::InvalidQuantile { q }, Language::Spanish) => {
+                format!("Cuantil inválido {}, debe estar entre 0 y 1", q)
+            },
+            (RavenError::TypeMismatch { column, expected, found }, Language::English) => {
+                format!("column {} has type {} but {} was requested", column, found, expected)
+            },
+            (RavenError::TypeMismatch { column, expected, found }, Language::Spanish) => {
+                format!("La columna {} es de tipo {} pero se solicitó {}", column, found, expected)
+            },
+            (RavenError::InvalidPattern { pattern, error }, Language::English) => {
+                format!("invalid pattern {}: {}", pattern, error)
+            },
+            (RavenError::InvalidPattern { pattern, error }, Language::Spanish) => {
+                format!("Patrón inválido {}: {}", pattern, error)
+            },
+            (RavenError::InvalidDateFormat { format, error }, Language::English) => {
+                format!("invalid date format {}: {}", format, error)
+            },
+            (RavenError::InvalidDateFormat { format, error }, Language::Spanish) => {
+                format!("Formato de fecha inválido {}: {}", format, error)
+            },
+            (RavenError::ValidationFailed { line, column, value, rule }, Language::English) => {
+                format!("value '{}' in column {} at line {} does not satisfy the rule {}", value, column, line, rule)
+            },
+            (RavenError::ValidationFailed { line, column, value, rule }, Language::Spanish) => {
+                format!("El valor '{}' de la columna {} en la línea {} no cumple la regla {}", value, column, line, rule)
+            },
+            (RavenError::NanFound { column }, Language::English) => {
+                format!("NaN value found in column {}", column)
+            },
+            (RavenError::NanFound { column }, Language::Spanish) => {
+                format!("Se encontró un valor NaN en la columna {}", column)
+            },
+            (RavenError::MissingArgument(n), Language::English) => {
+                format!("could not read the argument {}", n)
+            },
+            (RavenError::MissingArgument(n), Language::Spanish) => {
+                format!("No se pudo leer el argumento {}", n)
+            },
+            (RavenError::MalformedRow { line, byte, error }, Language::English) => {
+                format!("malformed record at line {}, byte {}: {}", line, byte, error)
+            },
+            (RavenError::MalformedRow { line, byte, error }, Language::Spanish) => {
+                format!("Registro mal formado en la línea {}, byte {}: {}", line, byte, error)
+            },
+            (RavenError::Io(err), Language::English) => format!("I/O error: {}", err),
+            (RavenError::Io(err), Language::Spanish) => format!("Error de entrada/salida: {}", err),
+            (RavenError::Csv(err), Language::English) => format!("CSV error: {}", err),
+            (RavenError::Csv(err), Language::Spanish) => format!("Error de CSV: {}", err),
+        }
+    }
+}
+
+impl fmt::Display for RavenError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.localized(language()))
+    }
+}
+
+impl Error for RavenError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            RavenError::Io(err) => Some(err),
+            RavenError::Csv(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<std::io::Error> for RavenError {
+    fn from(err: std::io::Error) -> RavenError {
+        RavenError::Io(err)
+    }
+}
+
+impl From<csv::Error> for RavenError {
+    /// The I/O errors of the csv crate, like opening a file which does not exist, are converted to `RavenError::Io`.
+    fn from(err: csv::Error) -> RavenError {
+        if !err.is_io_error() {
+            return RavenError::Csv(err)
+        }
+
+        match err.into_kind() {
+            csv::ErrorKind::Io(err) => RavenError::Io(err),
+            _ => unreachable!("is_io_error only holds for csv::ErrorKind::Io"),
+        }
+    }
+}
@@ -0,0 +1,101 @@
+//! Auxiliar module to compare and sort floating point values.
+//!
+//! Floating point types only implement `PartialOrd` because `NaN` is not comparable with any value, even with itself.
+//! The functions in this module order floats with an explicit `NanPolicy`, this allows to use `f32` and `f64` columns directly
+//! without wrapper types like the ones of the ordered-float crate.
+
+use std::cmp::Ordering;
+
+/// What to do with `NaN` values when floats are compared or sorted.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NanPolicy {
+    /// `NaN` values are left out of the result.
+    Ignore,
+    /// `NaN` values are smaller than any other value.
+    First,
+    /// `NaN` values are greater than any other value.
+    Last,
+    /// A `NaN` value produces an error.
+    Error,
+}
+
+/// Floating point types which can be parsed from a RawFrame and compared with a NanPolicy. It is implemented for `f32` and `f64`.
+pub trait Float: std::str::FromStr + PartialOrd + Copy {
+    /// Returns true if the value is `NaN`.
+    fn is_nan_value(self) -> bool;
+}
+
+impl Float for f32 {
+    fn is_nan_value(self) -> bool {
+        self.is_nan()
+    }
+}
+
+impl Float for f64 {
+    fn is_nan_value(self) -> bool {
+        self.is_nan()
+    }
+}
+
+/// Compares two floats with `NaN` values placed as the policy indicates. `NaN` values are equal between them.
+/// With the `Ignore` and `Error` policies `NaN` values are placed last, those policies must be applied before comparing.
+///
+/// # Examples
+///
+/// ```
+/// use ravencol::floats::{self, NanPolicy};
+/// use std::cmp::Ordering;
+///
+/// assert_eq!(floats::compare(f64::NAN, 1.0, NanPolicy::First), Ordering::Less);
+/// assert_eq!(floats::compare(f64::NAN, 1.0, NanPolicy::Last), Ordering::Greater);
+/// assert_eq!(floats::compare(2.0, 1.0, NanPolicy::Last), Ordering::Greater);
+/// ```
+pub fn compare<T: Float>(a: T, b: T, policy: NanPolicy) -> Ordering {
+    let orden = match (a.is_nan_value(), b.is_nan_value()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => return a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+    };
+
+    match policy {
+        NanPolicy::First => orden.reverse(),
+        _ => orden,
+    }
+}
+
+/// Compares two values of any partially ordered type without panicking. Values which are not comparable with themselves, like `NaN`,
+/// are greater than any other value.
+pub(crate) fn partial_cmp_last<T: PartialOrd>(a: &T, b: &T) -> Ordering {
+    let a_inc = a.partial_cmp(a).is_none();
+    let b_inc = b.partial_cmp(b).is_none();
+
+    match (a_inc, b_inc) {
+        (true, true) => Ordering::Equal,
+        (true, false) => Ordering::Greater,
+        (false, true) => Ordering::Less,
+        (false, false) => a.partial_cmp(b).unwrap_or(Ordering::Equal),
+    }
+}
+
+/// Applies the policy to the iterator. With `Ignore` the `NaN` values are filtered out and with `Error` a `NaN` value
+/// returns None. With `First` and `Last` the values are kept.
+pub(crate) fn apply_policy<T: Float>(iter: impl Iterator<Item=T>, policy: NanPolicy) -> Option<Vec<T>> {
+    match policy {
+        NanPolicy::Ignore => Some(iter.filter(|val| !val.is_nan_value()).collect()),
+        NanPolicy::Error => iter.map(|val| if val.is_nan_value() { None } else { Some(val) }).collect(),
+        NanPolicy::First | NanPolicy::Last => Some(iter.collect()),
+    }
+}
+
+/// Returns the minimum and maximum of an iterator of floats with the given policy in a single pass, or None if there are no values.
+pub(crate) fn extent<T: Float>(values: &[T], policy: NanPolicy) -> Option<(T, T)> {
+    let mut iter = values.iter().copied();
+    let first = iter.next()?;
+
+    Some(iter.fold((first, first), |(minimo, maximo), val| {
+        let minimo = if compare(val, minimo, policy) == Ordering::Less { val } else { minimo };
+        let maximo = if compare(val, maximo, policy) == Ordering::Greater { val } else { maximo };
+        (minimo, maximo)
+    }))
+}
@@ -0,0 +1,170 @@
+//! Grouping of records by key columns.
+//!
+//! A `GroupBy` splits the records of a RawFrame in groups with the same values in the key columns. Its `agg` method
+//! reduces each group to one record with the key values followed by the aggregations of the value columns, the result is
+//! a new RawFrame which can be converted to a typed DataFrame. The groups keep the order of their first record, an empty
+//! or missing key field is a key value like any other.
+//!
+//! Besides the built-in aggregations, custom aggregations can be added with a closure which receives all the fields of a
+//! column in the group and returns the aggregated field.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::aggregate::Aggregation;
+//!
+//! let datos: RawFrame = "region,ventas\nsur,10\nnorte,4\nsur,30\nnorte,\n".parse().unwrap();
+//!
+//! let resumen = datos.group_by(&["region"]).unwrap()
+//!     .custom("ventas", "filas", |valores| Some(valores.len().to_string()))
+//!     .agg(&[("ventas", Aggregation::Sum), ("ventas", Aggregation::Count)])
+//!     .unwrap();
+//!
+//! assert_eq!(resumen.columns.iter().collect::<Vec<&str>>(), vec!["region", "ventas_sum", "ventas_count", "filas"]);
+//! assert_eq!(resumen.records[0].iter().collect::<Vec<&str>>(), vec!["sur", "40", "2", "2"]);
+//! assert_eq!(resumen.records[1].iter().collect::<Vec<&str>>(), vec!["norte", "4", "1", "2"]);
+//! ```
+
+use crate::RavenError;
+use crate::aggregate::{Accumulator, Aggregation};
+use std::collections::HashMap;
+
+/// A closure which reduces the fields of a column in a group to one field.
+type CustomFn<'a> = Box<dyn Fn(&[&str]) -> Option<String> + 'a>;
+
+/// A custom aggregation of a column.
+struct Custom<'a> {
+    column: String,
+    name: String,
+    function: CustomFn<'a>,
+}
+
+/// The records of a RawFrame split in groups by the values of the key columns.
+pub struct GroupBy<'a> {
+    raw: &'a crate::RawFrame,
+    keys: Vec<usize>,
+    groups: Vec<Vec<usize>>,
+    custom: Vec<Custom<'a>>,
+}
+
+impl<'a> GroupBy<'a> {
+    /// Groups the records of a RawFrame by the key columns.
+    pub(crate) fn new(raw: &'a crate::RawFrame, keys: &[&str]) -> Result<GroupBy<'a>, RavenError> {
+        let positions = raw.col_positions(keys)?;
+        crate::check_unique(keys.iter().copied())?;
+
+        let mut indices: HashMap<Vec<&str>, usize> = HashMap::new();
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+
+        for (n, record) in raw.records.iter().enumerate() {
+            let llave: Vec<&str> = positions.iter().map(|position| record.get(*position).unwrap_or("")).collect();
+            let indice = *indices.entry(llave).or_insert_with(|| {
+                groups.push(Vec::new());
+                groups.len() - 1
+            });
+            groups[indice].push(n);
+        }
+
+        Ok(GroupBy { raw, keys: positions, groups, custom: Vec::new() })
+    }
+
+    /// Returns the number of groups.
+    pub fn len(&self) -> usize {
+        self.groups.len()
+    }
+
+    /// Returns true if there are no groups, that is if the RawFrame has no records.
+    pub fn is_empty(&self) -> bool {
+        self.groups.is_empty()
+    }
+
+    /// Adds a custom aggregation, its column is placed after the built-in aggregations in the result of `agg`.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - A string slice with the name of the column to aggregate
+    /// * `name` - A string slice with the name of the resulting column
+    /// * `function` - A closure which receives the fields of the column in the group, including the empty ones, and returns the aggregated field or None for an empty field
+    pub fn custom<F>(mut self, column: &str, name: &str, function: F) -> GroupBy<'a>
+    where F: Fn(&[&str]) -> Option<String> + 'a
+    {
+        self.custom.push(Custom {
+            column: String::from(column),
+            name: String::from(name),
+            function: Box::new(function),
+        });
+        self
+    }
+
+    /// Returns a new RawFrame with one record per group. The columns are the key columns, one column named
+    /// `column_aggregation` for each aggregation and the custom aggregations. An aggregation without valid values is an empty field.
+    ///
+    /// # Arguments
+    ///
+    /// * `aggs` - A slice of tuples with the name of each value column and its Aggregation
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::aggregate::Aggregation;
+    ///
+    /// let datos: RawFrame = "region,tienda,ventas\nsur,a,10\nsur,b,30\nsur,a,20\n".parse().unwrap();
+    ///
+    /// let resumen = datos.group_by(&["region"]).unwrap()
+    ///     .agg(&[("ventas", Aggregation::Median), ("tienda", Aggregation::NUnique)])
+    ///     .unwrap();
+    ///
+    /// assert_eq!(resumen.records[0].iter().collect::<Vec<&str>>(), vec!["sur", "20", "2"]);
+    /// ```
+    pub fn agg(&self, aggs: &[(&str, Aggregation)]) -> Result<crate::RawFrame, RavenError> {
+        let nombres: Vec<&str> = aggs.iter().map(|(nombre, _)| *nombre)
+            .chain(self.custom.iter().map(|custom| custom.column.as_str()))
+            .collect();
+        let positions = self.raw.col_positions(&nombres)?;
+        let (positions, custom_positions) = positions.split_at(aggs.len());
+
+        let mut columns = csv::StringRecord::new();
+        for position in &self.keys {
+            columns.push_field(&self.raw.columns[*position]);
+        }
+        for (nombre, aggregation) in aggs {
+            columns.push_field(&format!("{}_{}", nombre, aggregation));
+        }
+        for custom in &self.custom {
+            columns.push_field(&custom.name);
+        }
+        crate::check_unique(columns.iter())?;
+
+        let mut records = Vec::with_capacity(self.groups.len());
+
+        for group in &self.groups {
+            let primero = &self.raw.records[group[0]];
+
+            let mut record = csv::StringRecord::new();
+            for position in &self.keys {
+                record.push_field(primero.get(*position).unwrap_or(""));
+            }
+
+            for (position, (_, aggregation)) in positions.iter().zip(aggs.iter()) {
+                let mut acumulador = Accumulator::new(*aggregation);
+                for cadena in group.iter().filter_map(|n| self.raw.records[*n].get(*position)) {
+                    if !cadena.is_empty() {
+                        acumulador.push(cadena);
+                    }
+                }
+                record.push_field(&acumulador.result().unwrap_or_default());
+            }
+
+            for (position, custom) in custom_positions.iter().zip(self.custom.iter()) {
+                let valores: Vec<&str> = group.iter().map(|n| self.raw.records[*n].get(*position).unwrap_or("")).collect();
+                record.push_field(&(custom.function)(&valores).unwrap_or_default());
+            }
+
+            records.push(record);
+        }
+
+        Ok(crate::RawFrame { columns, records })
+    }
+}
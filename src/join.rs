@@ -0,0 +1,239 @@
+//! Relational joins between RawFrames.
+//!
+//! A join matches the records of two RawFrames by the values of one or more key columns which exist in both. The right
+//! RawFrame is indexed in a hash table, so the cost grows with the sum of the sizes of both RawFrames and the number of
+//! matches, not with their product.
+//!
+//! By default the keys are compared as written. With `JoinOptions::key_type` a key column is compared by value after
+//! parsing it to a `DataType`, in this way `007` and `7` are the same integer. Empty fields and values which are not
+//! possible to parse are null keys, a record with a null key never matches.
+//!
+//! The result has all the columns of the left RawFrame followed by the columns of the right RawFrame except its key
+//! columns. When a column name is in both RawFrames the suffixes are appended to both names. Semi and anti joins only keep
+//! the columns of the left RawFrame.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::dataframe::DataType;
+//! use ravencol::join::{JoinOptions, JoinType};
+//!
+//! let ventas: RawFrame = "clave,ventas\n007,10\n2,5\n9,1\n".parse().unwrap();
+//! let estados: RawFrame = "clave,estado\n7,Colima\n2,CDMX\n".parse().unwrap();
+//!
+//! let opciones = JoinOptions::new().key_type("clave", DataType::Int);
+//! let unidas = ventas.join_with(&estados, &["clave"], JoinType::Left, &opciones).unwrap();
+//!
+//! assert_eq!(unidas.columns.iter().collect::<Vec<&str>>(), vec!["clave", "ventas", "estado"]);
+//! assert_eq!(unidas.records[0].iter().collect::<Vec<&str>>(), vec!["007", "10", "Colima"]);
+//! assert_eq!(unidas.records[2].iter().collect::<Vec<&str>>(), vec!["9", "1", ""]);
+//! ```
+
+use crate::RavenError;
+use crate::dataframe::DataType;
+use crate::dates::Date;
+use std::collections::HashMap;
+
+/// Records kept by a join.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum JoinType {
+    /// Only the pairs of matching records.
+    Inner,
+    /// The pairs of matching records and the left records without a match.
+    Left,
+    /// The pairs of matching records and the right records without a match, in the order of the right RawFrame.
+    Right,
+    /// The pairs of matching records and the records of both RawFrames without a match.
+    Outer,
+    /// The left records with at least one match, each one only once.
+    Semi,
+    /// The left records without a match.
+    Anti,
+}
+
+/// Options of a join: the suffixes for clashing column names and the types used to compare the keys.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JoinOptions {
+    suffixes: (String, String),
+    key_types: Vec<(String, DataType)>,
+}
+
+impl Default for JoinOptions {
+    fn default() -> JoinOptions {
+        JoinOptions {
+            suffixes: (String::from("_left"), String::from("_right")),
+            key_types: Vec::new(),
+        }
+    }
+}
+
+impl JoinOptions {
+    /// Creates the default options: suffixes `_left` and `_right` and all the keys compared as written.
+    pub fn new() -> JoinOptions {
+        JoinOptions::default()
+    }
+
+    /// Sets the suffixes appended to the names of the columns which are in both RawFrames.
+    pub fn suffixes(mut self, left: &str, right: &str) -> JoinOptions {
+        self.suffixes = (String::from(left), String::from(right));
+        self
+    }
+
+    /// Sets the type used to compare the values of a key column. `DataType::Str` compares them as written.
+    pub fn key_type(mut self, column: &str, data_type: DataType) -> JoinOptions {
+        self.key_types.retain(|(nombre, _)| nombre != column);
+        self.key_types.push((String::from(column), data_type));
+        self
+    }
+}
+
+/// A hashable value of a key column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Llave<'a> {
+    Int(i64),
+    /// Bits of the float, with `-0.0` written as `0.0`.
+    Float(u64),
+    Bool(bool),
+    Date(Date),
+    Str(&'a str),
+}
+
+/// Returns the key of a field, None for null keys.
+fn llave(cell: Option<&str>, data_type: DataType) -> Option<Llave<'_>> {
+    let cadena = cell.filter(|cadena| !cadena.is_empty())?;
+
+    match data_type {
+        DataType::Int => cadena.parse().ok().map(Llave::Int),
+        DataType::Float => cadena.parse::<f64>().ok().filter(|num| !num.is_nan()).map(|num| Llave::Float((num + 0.0).to_bits())),
+        DataType::Bool => cadena.parse().ok().map(Llave::Bool),
+        DataType::Date => cadena.parse().ok().map(Llave::Date),
+        DataType::Str => Some(Llave::Str(cadena)),
+    }
+}
+
+/// Returns the key of a record, None if any of its fields is a null key.
+fn llaves<'a>(record: &'a csv::StringRecord, positions: &[usize], types: &[DataType]) -> Option<Vec<Llave<'a>>> {
+    positions.iter().zip(types.iter()).map(|(position, data_type)| llave(record.get(*position), *data_type)).collect()
+}
+
+/// Joins two RawFrames by the key columns.
+pub(crate) fn join(left: &crate::RawFrame, right: &crate::RawFrame, on: &[&str], how: JoinType, options: &JoinOptions) -> Result<crate::RawFrame, RavenError> {
+    let left_keys = left.col_positions(on)?;
+    let right_keys = right.col_positions(on)?;
+    crate::check_unique(on.iter().copied())?;
+
+    if let Some((nombre, _)) = options.key_types.iter().find(|(nombre, _)| !on.contains(&nombre.as_str())) {
+        return Err(RavenError::ColumnNotFound {
+            name: nombre.clone(),
+            available: on.iter().map(|col| String::from(*col)).collect(),
+        })
+    }
+
+    let types: Vec<DataType> = on.iter()
+        .map(|col| options.key_types.iter().find(|(nombre, _)| nombre == col).map_or(DataType::Str, |(_, data_type)| *data_type))
+        .collect();
+
+    let mut tabla: HashMap<Vec<Llave>, Vec<usize>> = HashMap::new();
+    for (n, record) in right.records.iter().enumerate() {
+        if let Some(llave) = llaves(record, &right_keys, &types) {
+            tabla.entry(llave).or_default().push(n);
+        }
+    }
+
+    let coincidencias: Vec<&[usize]> = left.records.iter()
+        .map(|record| llaves(record, &left_keys, &types).and_then(|llave| tabla.get(&llave)).map_or(&[][..], |filas| filas.as_slice()))
+        .collect();
+
+    if let JoinType::Semi | JoinType::Anti = how {
+        let semi = how == JoinType::Semi;
+        let records = left.records.iter().zip(coincidencias.iter())
+            .filter(|(_, filas)| filas.is_empty() != semi)
+            .map(|(record, _)| record.clone())
+            .collect();
+
+        return Ok(crate::RawFrame { columns: left.columns.clone(), records })
+    }
+
+    let right_values: Vec<usize> = (0..right.columns.len()).filter(|n| !right_keys.contains(n)).collect();
+
+    let mut columns = csv::StringRecord::new();
+    for (n, nombre) in left.columns.iter().enumerate() {
+        let repetida = !left_keys.contains(&n) && right_values.iter().any(|m| &right.columns[*m] == nombre);
+        if repetida {
+            columns.push_field(&format!("{}{}", nombre, options.suffixes.0));
+        } else {
+            columns.push_field(nombre);
+        }
+    }
+    for m in &right_values {
+        let nombre = &right.columns[*m];
+        let repetida = left.columns.iter().enumerate().any(|(n, col)| col == nombre && !left_keys.contains(&n));
+        if repetida {
+            columns.push_field(&format!("{}{}", nombre, options.suffixes.1));
+        } else {
+            columns.push_field(nombre);
+        }
+    }
+    crate::check_unique(columns.iter())?;
+
+    let unir = |izquierdo: Option<&csv::StringRecord>, derecho: Option<&csv::StringRecord>| {
+        let mut record = csv::StringRecord::new();
+        for n in 0..left.columns.len() {
+            let campo = match (izquierdo, derecho) {
+                (Some(izquierdo), _) => izquierdo.get(n),
+                (None, Some(derecho)) => left_keys.iter().position(|key| *key == n).and_then(|k| derecho.get(right_keys[k])),
+                (None, None) => None,
+            };
+            record.push_field(campo.unwrap_or(""));
+        }
+        for m in &right_values {
+            record.push_field(derecho.and_then(|derecho| derecho.get(*m)).unwrap_or(""));
+        }
+        record
+    };
+
+    let mut records = Vec::new();
+
+    if how == JoinType::Right {
+        let mut inversas: Vec<Vec<usize>> = vec![Vec::new(); right.records.len()];
+        for (n, filas) in coincidencias.iter().enumerate() {
+            for m in filas.iter() {
+                inversas[*m].push(n);
+            }
+        }
+
+        for (derecho, filas) in right.records.iter().zip(inversas.iter()) {
+            if filas.is_empty() {
+                records.push(unir(None, Some(derecho)));
+            }
+            for n in filas {
+                records.push(unir(Some(&left.records[*n]), Some(derecho)));
+            }
+        }
+
+        return Ok(crate::RawFrame { columns, records })
+    }
+
+    let mut usadas = vec![false; right.records.len()];
+
+    for (izquierdo, filas) in left.records.iter().zip(coincidencias.iter()) {
+        if filas.is_empty() && how != JoinType::Inner {
+            records.push(unir(Some(izquierdo), None));
+        }
+        for m in filas.iter() {
+            usadas[*m] = true;
+            records.push(unir(Some(izquierdo), Some(&right.records[*m])));
+        }
+    }
+
+    if how == JoinType::Outer {
+        for (derecho, usada) in right.records.iter().zip(usadas.iter()) {
+            if !usada {
+                records.push(unir(None, Some(derecho)));
+            }
+        }
+    }
+
+    Ok(crate::RawFrame { columns, records })
+}
@@ -14,10 +14,13 @@
 //! 
 //! ## RawFrame construction
 //! 
-//! Currently RawFrames can only be built from CSV files. To build a RawFrame the path to the file is required. There are 2 functions to create RawFrames:
+//! Currently RawFrames can only be built from CSV data. The data can come from a file, from any reader or from memory. There are 5 functions to create RawFrames:
 //! 
 //! - RawFrame from an OsString: `RawFrame::from_os_string(file_path: OsString)`
 //! - RawFrame from the nth argument when the binary is executed: `RawFrame::from_arg(n: usize)`
+//! - RawFrame from any type implementing `std::io::Read`: `RawFrame::from_reader(reader: R)`
+//! - RawFrame from a string slice: `RawFrame::from_str(data: &str)` or `data.parse::<RawFrame>()`
+//! - RawFrame from the standard input: `RawFrame::from_stdin()`
 //! 
 //! ### Example of loading a CSV file
 //! ~~~rust
@@ -28,37 +31,45 @@
 //! let datos = RawFrame::from_os_string(path).unwrap();
 //! ~~~
 //! 
+//! By default the file is read with a comma delimiter, a header row and trimming all the fields. For other formats use `RawFrame::builder()` to configure the delimiter, quote, escape, comment prefix, header, trim policy, rows to skip and maximum number of rows:
+//! ~~~rust
+//! use std::ffi::OsString;
+//! use ravencol::RawFrame;
+//! 
+//! let path = OsString::from("./datos_test/test_semicolon.csv");
+//! let datos = RawFrame::builder().delimiter(b';').skip_rows(2).comment(Some(b'#')).read_os_string(path).unwrap();
+//! ~~~
+//! 
+//! Missing values are often written with tokens like `NA`, `N/A` or `-`. With `null_values(tokens)` those fields are loaded as empty fields, seen as `Datum::Empty`, in every column and with `column_null_values(column, tokens)` a column uses its own tokens. Empty fields are null values for all the accessors, `col_counts::<T>(column)` counts separately the null values and the values that are not possible to parse.
+//! 
+//! Rows that can not be read, for example rows with invalid UTF-8, are skipped. To know which rows were skipped use `read_os_string_report`, it returns a `LoadReport` with the line, byte offset and error of each malformed row. With `bad_rows(BadRowPolicy::Fail)` the load stops at the first malformed row and with `bad_rows(BadRowPolicy::Quarantine)` the raw rows are kept in a quarantine RawFrame inside the report.
+//! 
 //! Each RawFrame has two elements:
 //! - `columns` where the names of the columns obtained from the first row of the CSV file are stored
 //! - `records` where all records are stored as a vector of rows.
 //! 
-//! If our data is in several files there is the possibility of concatenating RawFrames, this operation modifies the base RawFrame and consumes the target RawFrame. Up to now the function to concatenate only checks that the number of columns is the same in both DataFrames, It is the user's responsibility to assure that the columns of both RawFrames have semantic sense and are in the same order.
+//! If our data is in several files there is the possibility of concatenating RawFrames, this operation modifies the base RawFrame and consumes the target RawFrame. The function `concat` only checks that the number of columns is the same in both DataFrames, it is the user's responsibility to assure that the columns of both RawFrames have semantic sense and are in the same order. The function `concat_by_name` matches the columns by name and reorders the records of the target RawFrame, with `ConcatMode::Union` the missing columns are filled with empty fields, with `ConcatMode::Intersection` only the common columns are kept and with `ConcatMode::Strict` an error lists the differences.
 //! 
 //! ### Example to load all files from a directory
 //! 
-//! The following example assumes that the path to the directory is provided in the execution position 1 argument and that it is filled with only CSV files with the same kind of columns
+//! All the CSV files of a directory, or all the files matching a glob pattern, can be loaded and concatenated by column name with `from_dir(dir_path)` and `from_glob(pattern)`. The files are parsed in parallel and concatenated in alphabetical order of their paths. The FilesReport lists the loaded files and the files that failed with their error. With the builder it is possible to add a column with the path of the file of each record:
 //! ```no_run
-//! use ravencol::reading;
-//! use std::ffi::OsString;
 //! use ravencol::RawFrame;
-//! use std::fs;
 //! 
-//! let directorio = reading::read_arg(1).unwrap();
+//! let (datos, report) = RawFrame::builder().source_column(Some("source_file")).read_glob("data/*.csv").unwrap();
 //! 
-//! let mut paths = fs::read_dir(directorio).unwrap().map(|path| path.unwrap().path().into_os_string());
-//! let mut base = RawFrame::from_os_string(paths.next().unwrap()).unwrap();
+//! for failure in &report.failed {
+//!     eprintln!("{}: {}", failure.path.display(), failure.error);
+//! }
+//! ```
 //! 
-//! loop {
+//! ### Validation with a declared schema
 //! 
-//!    let file = match paths.next() {
-//!        Some(rec) => rec,
-//!        None => break,
-//!     };
+//! When the expected content of a file is known it can be declared with a `DeclaredSchema`: the type of each column, if it can have null values and rules like an allowed range, a regular expression or a set of values. `RawFrame::from_os_string_with_schema(file_path, schema, mode)` loads the file and validates every record, it returns the RawFrame and a `ValidationReport` with the row, column, value and rule of each violation. The `ValidationMode` rejects the rows with violations, replaces the invalid values by null values or aborts the load.
 //! 
-//!     base.concat(RawFrame::from_os_string(file).unwrap()).unwrap();
+//! ## Errors
 //! 
-//! }
-//! ```
+//! All the fallible functions return a `RavenError`. It is an enum, in this way it is possible to match on the kind of failure, for example `RavenError::ColumnNotFound` holds the requested name and the available columns. The messages are in English by default, they can be shown in Spanish with `errors::set_language(errors::Language::Spanish)`.
 //! 
 //! ## Column creation
 //! 
@@ -133,16 +144,24 @@
 //! 
 //! ### Create generic columns
 //! 
-//! You can also create a generic column trying to identify if the data in each row of the column to be generated is an integer, a float, a string or a null value. For this we use the Enum Datum that was created to represent a generic data.
+//! You can also create a generic column trying to identify if the data in each row of the column to be generated is an integer, a float, a boolean, a date, a date time, a string or a null value. For this we use the Enum Datum that was created to represent a generic data.
 //! ~~~rust 
+//! # use ravencol::dates::{Date, DateTime};
 //! enum Datum<'a> {
 //!     Integer(i32),
+//!     Int64(i64),
 //!     Float(f64),
+//!     Bool(bool),
+//!     Date(Date),
+//!     DateTime(DateTime),
 //!     NotNumber(&'a str),
+//!     Empty,
 //!     None
 //! };
 //! ~~~
 //! 
+//! An empty field is `Empty` and a field missing from a short record is `None`, both can be checked with `is_null()`.
+//! 
 //! To create a generic column of this type we use the function `column (column)` where the argument `column` is the name of the column to obtain.
 //! ~~~rust
 //! use std::ffi::OsString;
@@ -160,6 +179,85 @@
 //! let columna: Vec<Datum> = datos.column("col_a").unwrap().collect();
 //! ~~~
 //! 
+//! ### Numbers in other formats
+//! 
+//! Numbers with thousand separators, decimal commas, percentages or currency symbols, like `1,234.50`, `1.234,50`, `12%` or `$3.20`, are not possible to parse with the plain accessors. A `NumberFormat` describes how the numbers are written and can be used with `col_type_with`, `col_fil_with`, `col_imp_with` and `column_with`:
+//! ~~~rust
+//! use ravencol::RawFrame;
+//! use ravencol::numbers::NumberFormat;
+//! 
+//! let datos = RawFrame::builder().delimiter(b';').read_str("monto\n$1.234,50\n$20\n").unwrap();
+//! let formato = NumberFormat::european().currency(&["$"]);
+//! 
+//! let total: f64 = datos.col_fil_with::<f64>("monto", &formato).unwrap().sum();
+//! ~~~
+//! 
+//! ### Dates and time series
+//! 
+//! Dates and date times are read with `col_date(column, format)` where format is a strftime-style format like `%d/%m/%Y` or None to detect it from a list of common formats. The result is a column of `Date` or `DateTime`, the type used in the definition of the variable. A `DateTime` keeps its offset from UTC and it is compared by the instant it represents.
+//! 
+//! To plot a time series the methods `pair_col_fil_date`, `pair_col_imp_date`, `slice_col_fil_date` and `slice_col_imp_date` return the rows sorted by a date column together with the values of other columns:
+//! ~~~rust
+//! use ravencol::RawFrame;
+//! use ravencol::dates::Date;
+//! 
+//! let datos: RawFrame = "fecha,casos\n2020-03-02,5\n2020-03-01,3\n".parse().unwrap();
+//! 
+//! let serie: Vec<(Date, f64)> = datos.pair_col_fil_date("fecha", "casos", None).unwrap().collect();
+//! ~~~
+//! 
+//! To group a time series by day, week, month or quarter use `resample(date_column, format, frequency, aggs, fill)`. It returns a new RawFrame with one row per period and the aggregations (sum, mean, median, min, max, count, first, last or number of distinct values) of the value columns, the periods without rows can be left out or filled with null or imputed values.
+//! 
+//! ### Parse a column once
+//! 
+//! All the column accessors parse the cells of the RawFrame every time they are called. When a column is used several times it can be parsed once into a `Series<T>` with `series(column)`. A Series keeps the parsed values and a validity bitmap that marks the values that were not possible to parse. Several columns can be parsed into a typed `DataFrame` with `to_dataframe(schema)`, and a DataFrame can be converted back to a RawFrame with `to_raw()`.
+//! 
+//! To know the type of each column before parsing it use `infer_schema(sample_rows)`, it returns a `Schema` with the dominant type of each column (int, float, bool, date or string), if it has null values and the ratio of values that do not conform to that type. The types of a Schema can be used directly with `to_dataframe(&schema.types())`.
+//! 
+//! ### Select, drop, rename and reorder columns
+//! 
+//! The methods `select(columns)`, `drop(columns)`, `rename(old, new)` and `reorder(columns)` return a new RawFrame where the header and the records stay consistent. They are useful to align RawFrames with different columns before concatenating them.
+//! 
+//! ### Filter rows
+//! 
+//! Applying filters to columns one by one makes the columns drift apart. To keep or drop whole records use `filter(predicate)`, where the predicate receives a `Row` which reads its fields by column name, or `filter_by(column, predicate)`, where the predicate receives the parsed value of one column. Both return a new RawFrame, so all the later column accessors stay aligned by row:
+//! ~~~rust
+//! use ravencol::RawFrame;
+//! 
+//! let datos: RawFrame = "estado,poblacion\nCDMX,9209944\nColima,731391\n".parse().unwrap();
+//! 
+//! let grandes = datos.filter_by("poblacion", |pob: Option<i64>| pob.map_or(false, |pob| pob > 1000000)).unwrap();
+//! let norte = datos.filter(|row| row.get("estado") != Some("Colima"));
+//! ~~~
+//! 
+//! ### Sort records
+//! 
+//! To sort the whole records use `sort_by(keys)` with a slice of `SortKey`. Each key has a column, the type used to compare its values (numbers, booleans and dates are compared by value and strings lexicographically), the direction and the position of the null values. The sort is stable and it does not panic with `NaN` values, they are sorted as null values.
+//! 
+//! ### Summary statistics
+//! 
+//! The first look at a new dataset is `describe()`. It returns a Summary with the inferred type of each column, the number of values, null values and values that are not possible to parse, the number of distinct values, the most frequent values and, for numeric columns, the mean, standard deviation, minimum, quartiles and maximum. The Summary prints as a table and it can be converted with `to_rawframe()` or written with `to_csv(path)`.
+//! 
+//! ### Group records
+//! 
+//! To aggregate by key use `group_by(keys)`, its `agg(aggs)` method returns a new RawFrame with one record per group, the key columns and the aggregations (sum, mean, median, min, max, count, first, last or number of distinct values) of the value columns. Custom aggregations are added with `custom(column, name, closure)`, the closure receives the fields of the column in each group.
+//! 
+//! ### Join RawFrames
+//! 
+//! To add the columns of a lookup RawFrame use `join(other, on, how)` with one or more key columns. The `JoinType` can be inner, left, right, outer, semi or anti. The join is hash-based. With `join_with(other, on, how, options)` the `JoinOptions` set the suffixes for the column names which are in both RawFrames and the type used to compare each key, in this way `007` and `7` can be matched as integers.
+//! 
+//! ### Reshape records
+//! 
+//! Wide data, with one column per variable, is turned into long data with `melt(id_cols, value_cols, var_name, value_name)`. The reverse is `pivot(index, columns, values, agg)`, the distinct values of the `columns` column become the names of the new columns and the duplicate cells are reduced with the aggregation.
+//! 
+//! ### Statistics of columns
+//! 
+//! The reductions `mean`, `variance`, `std`, `median`, `quantile`, `mode`, `sum` and `product` are available for numeric columns with the `_fil` suffix, which leaves out the values that are not possible to parse, and the `_imp` suffix, which imputes them, for example `mean_fil::<f64>(column)` or `quantile_imp(column, q, method, none_val)`. The same reductions are available on any iterator of numbers, like the ones returned by `col_fil`, with the extension trait `stats::Stats`. The mean and the variance use Welford's algorithm.
+//! 
+//! ### Correlation and covariance
+//! 
+//! `corr(columns, method)` returns the Pearson or Spearman correlation matrix of numeric columns and `cov(columns)` the sample covariance matrix. By default each pair of columns uses the records where both values exist, with `corr_with` and `cov_with` the `MissingPolicy::Listwise` uses only the records without missing values. The result is a labelled square Matrix which prints as a table and can be converted with `to_rawframe()`.
+//! 
 //! ### Create column sets
 //! 
 //! There are times when iterators that contain data sets from multiple columns are needed. For example, to plot points we would need pairs of coordinates. Within RavenCol there are methods to obtain these sets of data. The logic is the same, select the type of data and define what to do with the values ​​that it is not possible to represent in that type. Up to now all values ​​must have the same type, if structures with different types of data are needed it is possible to use the Datum type and then process it.
@@ -168,15 +266,17 @@
 //! 
 //! A special case is the creation of pairs of columns since they are used in the creation of plots, for those cases we have the methods `pair_col_fil (xcolumn, ycolumn)` and `pair_col_imp (xcolumn, ycolumn, none_val_x, none_val_y)` both methods return iterators with tuples of values. For the special case of plots of joined points, i.e. line plotting, methods are provided that order the elements of the iterator considering the first column. These methods are `pair_col_fil_sorted (xcolumn, ycolumn)` and `pair_col_imp_sorted (xcolumn, ycolumn, none_val_x, none_val_y)`. Sorting capabilities are basic, always in terms of the first column and always in ascending order. If more complex orderings are required they can be done with the iterator manipulation capabilities provided by Rust.
 //! 
+//! Floats only implement `PartialOrd` because of `NaN` values. For `f32` and `f64` columns there are the methods `max_float_fil`, `min_float_fil`, `extent_float_fil`, `pair_col_fil_sorted_float` and `pair_col_imp_sorted_float`, they receive a `NanPolicy` to ignore the `NaN` values, to place them first or last or to return an error.
+//! 
 //! ### Example of how to plot using [plotters](https://github.com/38/plotters)
 //! 
 //! ```ignore
 //! use std::error::Error;
 //! use std::process;
-//! use ordered_float::OrderedFloat;
 //! use plotters::prelude::*;
 //! 
 //! use ravencol::RawFrame;
+//! use ravencol::floats::NanPolicy;
 //! 
 //! fn main() {
 //!     if let Err(err) = run() {
@@ -193,11 +293,11 @@
 //!     let col_x = "Reservations";
 //!     let col_y = "Pizzas";
 //! 
-//!     let extent_x: (OrderedFloat<f64>,OrderedFloat<f64>) = datos.extent_num_fil(col_x)?;
-//!     let extent_y: (OrderedFloat<f64>,OrderedFloat<f64>) = datos.extent_num_fil(col_y)?;
+//!     let extent_x: (f64,f64) = datos.extent_float_fil(col_x, NanPolicy::Ignore)?;
+//!     let extent_y: (f64,f64) = datos.extent_float_fil(col_y, NanPolicy::Ignore)?;
 //! 
-//!     let x_range = extent_x.0.into_inner()..extent_x.1.into_inner();
-//!     let y_range = extent_y.0.into_inner()..extent_y.1.into_inner();
+//!     let x_range = extent_x.0..extent_x.1;
+//!     let y_range = extent_y.0..extent_y.1;
 //! 
 //! 
 //!     let drawing_area = BitMapBackend::new("./test.png", (1024, 768)).into_drawing_area();
@@ -233,18 +333,193 @@
 //! ```
 //! 
 //! 
-use std::error::Error;
 use std::ffi::OsString;
 
-/// Enum to contain a datum, it can be an Integer, a Float, an String or None.
+pub mod aggregate;
+pub mod correlation;
+pub mod dataframe;
+pub mod dates;
+pub mod describe;
+pub mod errors;
+pub mod floats;
+pub mod groupby;
+pub mod join;
+pub mod numbers;
+pub mod resample;
+pub mod reshape;
+pub mod schema;
+pub mod series;
+pub mod sort;
+pub mod stats;
+pub mod validation;
+
+pub use errors::RavenError;
+
+/// Enum to contain a datum, it can be an Integer, a Float, a Bool, a Date, a DateTime, an String, an empty field or None.
+///
+/// Integers that fit in an i32 are `Integer`, larger integers are `Int64` so they do not lose precision.
+/// `Empty` is a field which exists but has no content, `None` is a field missing from a short record.
 #[derive(Debug, PartialEq)]
 pub enum Datum<'a> {
     Integer(i32),
+    Int64(i64),
     Float(f64),
+    Bool(bool),
+    Date(dates::Date),
+    DateTime(dates::DateTime),
     NotNumber(&'a str),
+    Empty,
     None
 }
 
+impl<'a> Datum<'a> {
+    /// Returns the Datum which represents the content of a field, None is a missing field.
+    ///
+    /// The detection tries in order: empty field, i32, i64, f64, bool (`true` or `false`), ISO date, ISO date time and finally string.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - An Option with the string slice of the field
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::Datum;
+    ///
+    /// assert_eq!(Datum::detect(Some("3000000000")), Datum::Int64(3000000000));
+    /// assert_eq!(Datum::detect(Some("true")), Datum::Bool(true));
+    /// assert_eq!(Datum::detect(Some("")), Datum::Empty);
+    /// assert_eq!(Datum::detect(None), Datum::None);
+    /// ```
+    pub fn detect(cell: Option<&'a str>) -> Datum<'a> {
+        Datum::detect_with(cell, &numbers::NumberFormat::default())
+    }
+
+    /// Returns the Datum which represents the content of a field reading the numbers with a NumberFormat.
+    ///
+    /// # Arguments
+    ///
+    /// * `cell` - An Option with the string slice of the field
+    /// * `format` - The NumberFormat of the numbers
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::Datum;
+    /// use ravencol::numbers::NumberFormat;
+    ///
+    /// let formato = NumberFormat::english().currency(&["$"]);
+    ///
+    /// assert_eq!(Datum::detect_with(Some("$1,234.50"), &formato), Datum::Float(1234.5));
+    /// assert_eq!(Datum::detect_with(Some("1,234"), &formato), Datum::Integer(1234));
+    /// ```
+    pub fn detect_with(cell: Option<&'a str>, format: &numbers::NumberFormat) -> Datum<'a> {
+        let cadena = match cell {
+            None => return Datum::None,
+            Some("") => return Datum::Empty,
+            Some(cadena) => cadena,
+        };
+
+        let numero = format.normalize(cadena).unwrap_or(std::borrow::Cow::Borrowed(""));
+
+        if let Ok(num) = numero.parse::<i32>() {
+            Datum::Integer(num)
+        } else if let Ok(num) = numero.parse::<i64>() {
+            Datum::Int64(num)
+        } else if let Ok(num) = numero.parse::<f64>() {
+            Datum::Float(num)
+        } else if let Ok(val) = cadena.parse::<bool>() {
+            Datum::Bool(val)
+        } else if let Ok(fecha) = cadena.parse::<dates::Date>() {
+            Datum::Date(fecha)
+        } else if let Ok(fecha) = cadena.parse::<dates::DateTime>() {
+            Datum::DateTime(fecha)
+        } else {
+            Datum::NotNumber(cadena)
+        }
+    }
+
+    /// Returns true if the Datum is an empty field or a missing field.
+    pub fn is_null(&self) -> bool {
+        matches!(self, Datum::Empty | Datum::None)
+    }
+}
+
+/// Number of valid, null and invalid values in a column, see `RawFrame::col_counts`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ColumnCounts {
+    /// Values which can be parsed to the requested type.
+    pub valid: usize,
+    /// Empty and missing fields.
+    pub null: usize,
+    /// Values which are not null but can not be parsed to the requested type.
+    pub invalid: usize,
+}
+
+/// A row of a RawFrame which allows to read its fields by the name of the column. It is used by `RawFrame::filter`.
+#[derive(Debug, Clone, Copy)]
+pub struct Row<'a> {
+    columns: &'a csv::StringRecord,
+    record: &'a csv::StringRecord,
+}
+
+impl<'a> Row<'a> {
+    /// Returns the field of a column or None if the column does not exist or the field is missing.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    pub fn get(&self, column: &str) -> Option<&'a str> {
+        let position = self.columns.iter().position(|col| col == column)?;
+        self.record.get(position)
+    }
+
+    /// Returns the field of a column parsed to a generic type, or None if it is not possible to parse it.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    pub fn parse<T: std::str::FromStr>(&self, column: &str) -> Option<T> {
+        self.get(column)?.parse::<T>().ok()
+    }
+
+    /// Returns the field of a column as a Datum.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    pub fn datum(&self, column: &str) -> Datum<'a> {
+        Datum::detect(self.get(column))
+    }
+
+    /// Returns the StringRecord of the row.
+    pub fn record(&self) -> &'a csv::StringRecord {
+        self.record
+    }
+}
+
+/// How to combine the columns of two RawFrames concatenated with `RawFrame::concat_by_name`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConcatMode {
+    /// All the columns of both RawFrames are kept, the columns missing in a RawFrame are filled with empty fields.
+    Union,
+    /// Only the columns present in both RawFrames are kept.
+    Intersection,
+    /// Both RawFrames must have the same columns, in any order. Otherwise an error lists the differences.
+    Strict,
+}
+
+/// Returns an error with the first name which appears more than once.
+fn check_unique<'a>(names: impl Iterator<Item=&'a str>) -> Result<(), RavenError> {
+    let mut vistos = std::collections::HashSet::new();
+    for name in names {
+        if !vistos.insert(name) {
+            return Err(RavenError::DuplicateColumn { name: String::from(name) })
+        }
+    }
+    Ok(())
+}
+
 /// Main data struct. It contains a vec of StringRecords and the name of the columns from the CSV file.
 /// 
 /// The normal way of creating a RawFrame is from a CSV file. This file will be parsed with CSV crate functions.
@@ -282,20 +557,163 @@ impl RawFrame {
     /// let path = OsString::from("./datos_test/test.csv");
     /// let datos = RawFrame::from_os_string(path).unwrap();
     /// ```
-    pub fn from_os_string(file_path: OsString) -> Result<crate::RawFrame, Box<dyn Error>> {
+    pub fn from_os_string(file_path: OsString) -> Result<crate::RawFrame, RavenError> {
+
+        crate::RawFrame::from_os_string_with(file_path, &crate::reading::ReadOptions::default())
+
+    }
+
+    /// Creates a RawFrame from an os_string parsing the CSV file with the given options.
+    /// 
+    /// # Arguments
+    ///
+    /// * `file_path` - An OsString that holds the path of CSV file
+    /// * `options` - A ReadOptions with the delimiter, quoting, header, trim and row limits to use
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::reading::ReadOptions;
+    /// use std::ffi::OsString;
+    ///
+    /// let path = OsString::from("./datos_test/test.csv");
+    /// let datos = RawFrame::from_os_string_with(path, ReadOptions::new().max_rows(Some(2))).unwrap();
+    ///
+    /// assert_eq!(datos.records.len(),2);
+    /// ```
+    pub fn from_os_string_with(file_path: OsString, options: &crate::reading::ReadOptions) -> Result<crate::RawFrame, RavenError> {
+
+        let (columns,records) = crate::reading::get_data_opt(file_path, options)?;
+
+        Ok(crate::RawFrame{columns, records})
+
+    }
+
+    /// Creates a RawFrame from an os_string and validates each record with a declared schema.
+    /// It returns the RawFrame with the rows and values kept by the validation mode and a report with all the violations.
+    /// 
+    /// # Arguments
+    ///
+    /// * `file_path` - An OsString that holds the path of CSV file
+    /// * `schema` - A DeclaredSchema with the type, nullability and rules of the columns to validate
+    /// * `mode` - A ValidationMode to reject the rows with violations, to replace the invalid values by null values or to abort
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dataframe::DataType;
+    /// use ravencol::validation::{ColumnSpec, DeclaredSchema, ValidationMode};
+    /// use std::ffi::OsString;
+    ///
+    /// let schema = DeclaredSchema::new()
+    ///     .column(ColumnSpec::new("col_a", DataType::Int).nullable(false))
+    ///     .column(ColumnSpec::new("col_b", DataType::Int).range(Some(0.0), Some(20.0)));
+    ///
+    /// let path = OsString::from("./datos_test/test.csv");
+    /// let (datos, report) = RawFrame::from_os_string_with_schema(path, &schema, ValidationMode::CoerceNull).unwrap();
+    ///
+    /// assert_eq!(datos.records.len(), 4);
+    /// assert_eq!(report.violations.len(), 3);
+    /// assert_eq!(report.violations[0].line, 2);
+    /// ```
+    pub fn from_os_string_with_schema(file_path: OsString, schema: &validation::DeclaredSchema, mode: validation::ValidationMode) -> Result<(crate::RawFrame,validation::ValidationReport), RavenError> {
+
+        let datos = crate::RawFrame::from_os_string(file_path)?;
+
+        datos.validate(schema, mode)
+
+    }
+
+    /// Returns a ReadOptions with the default values to configure how a CSV file is parsed into a RawFrame.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use std::ffi::OsString;
+    ///
+    /// let path = OsString::from("./datos_test/test.csv");
+    /// let datos = RawFrame::builder().delimiter(b',').comment(Some(b'#')).read_os_string(path).unwrap();
+    ///
+    /// assert_eq!(datos.columns.get(1),Some("col_b"));
+    /// ```
+    pub fn builder() -> crate::reading::ReadOptions {
+        crate::reading::ReadOptions::new()
+    }
+
+    /// Creates a RawFrame from any type implementing std::io::Read, like a file, a network stream or a decompressed archive entry.
+    /// 
+    /// # Arguments
+    ///
+    /// * `reader` - A type implementing std::io::Read with the CSV data
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use std::io::Cursor;
+    ///
+    /// let cursor = Cursor::new(b"col_a,col_b\n1,2\n3,4\n".to_vec());
+    /// let datos = RawFrame::from_reader(cursor).unwrap();
+    ///
+    /// assert_eq!(datos.records.len(),2);
+    /// ```
+    pub fn from_reader<R: std::io::Read>(reader: R) -> Result<crate::RawFrame, RavenError> {
+
+        crate::RawFrame::from_reader_with(reader, &crate::reading::ReadOptions::default())
+
+    }
+
+    /// Creates a RawFrame from any type implementing std::io::Read parsing the CSV data with the given options.
+    /// 
+    /// # Arguments
+    ///
+    /// * `reader` - A type implementing std::io::Read with the CSV data
+    /// * `options` - A ReadOptions with the delimiter, quoting, header, trim and row limits to use
+    pub fn from_reader_with<R: std::io::Read>(reader: R, options: &crate::reading::ReadOptions) -> Result<crate::RawFrame, RavenError> {
 
-        let (columns,records) = crate::reading::get_data_src_h(file_path)?;
+        let (columns,records) = crate::reading::get_data_rdr(reader, options)?;
 
         Ok(crate::RawFrame{columns, records})
 
     }
 
+    /// Creates a RawFrame concatenating all the CSV files which match a glob pattern, like `data/*.csv`, with the default options.
+    /// The files are loaded in parallel and concatenated by column name in alphabetical order of their paths.
+    /// It returns the RawFrame and a FilesReport with the files that could not be loaded. Use `builder().source_column(..).read_glob(..)`
+    /// to add a column with the path of the file of each record.
+    /// 
+    /// # Arguments
+    ///
+    /// * `pattern` - A string slice with the glob pattern
+    pub fn from_glob(pattern: &str) -> Result<(crate::RawFrame,crate::reading::FilesReport), RavenError> {
+        crate::reading::ReadOptions::default().read_glob(pattern)
+    }
+
+    /// Creates a RawFrame concatenating all the files with `csv` extension of a directory with the default options, see `from_glob`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `dir_path` - An OsString that holds the path of the directory
+    pub fn from_dir(dir_path: OsString) -> Result<(crate::RawFrame,crate::reading::FilesReport), RavenError> {
+        crate::reading::ReadOptions::default().read_dir(dir_path)
+    }
+
+    /// Creates a RawFrame from the standard input. It allows to pipe CSV data into a binary, like `cat datos.csv | binary`.
+    pub fn from_stdin() -> Result<crate::RawFrame, RavenError> {
+
+        crate::RawFrame::from_reader(std::io::stdin().lock())
+
+    }
+
     /// Creates a RawFrame from terminal argument in position n.
     /// 
     /// # Arguments
     ///
     /// * `n` - A usize that holds the position of the terminal argument on which is the path of CSV file
-    pub fn from_arg(n: usize) -> Result<crate::RawFrame, Box<dyn Error>> {
+    pub fn from_arg(n: usize) -> Result<crate::RawFrame, RavenError> {
 
         let ruta = crate::reading::read_arg(n)?;
         let datos = crate::RawFrame::from_os_string(ruta)?;
@@ -304,10 +722,13 @@ impl RawFrame {
 
     }
 
-    pub fn concat(&mut self, cola: crate::RawFrame) -> Result<(), Box<dyn Error>> {
+    pub fn concat(&mut self, cola: crate::RawFrame) -> Result<(), RavenError> {
 
-        if &self.columns.len() != &cola.columns.len() {
-            return Err(From::from("El número de columnas no es el mismo"))
+        if self.columns.len() != cola.columns.len() {
+            return Err(RavenError::SchemaMismatch {
+                expected: self.columns.iter().map(String::from).collect(),
+                found: cola.columns.iter().map(String::from).collect(),
+            })
         }
 
         self.records.extend(cola.records);
@@ -315,136 +736,496 @@ impl RawFrame {
         Ok(())
     }
 
-    /// Returns the position index for column in RawFrame or None if column does not exists.
+    /// Concatenates the records of another RawFrame matching the columns by name, the records of the target RawFrame are reordered to follow the header of the base RawFrame.
+    /// This operation modifies the base RawFrame and consumes the target RawFrame. Missing fields are empty fields.
     /// 
     /// # Arguments
     ///
-    /// * `column` - A string slice that holds the name of the column
+    /// * `cola` - The RawFrame with the records to add
+    /// * `mode` - A ConcatMode to keep the union or the intersection of the columns, or to require the same columns
     ///
     /// # Examples
     ///
     /// ```
-    /// use ravencol::RawFrame;
-    /// use std::ffi::OsString;
+    /// use ravencol::{ConcatMode, RawFrame};
     ///
-    /// fn get_data() -> ravencol::RawFrame {
-    ///     let path = OsString::from("./datos_test/test.csv");
-    ///     let datos = RawFrame::from_os_string(path).unwrap();
-    ///     datos
-    /// } 
+    /// let mut enero: RawFrame = "fecha,ventas\n2020-01-01,10\n".parse().unwrap();
+    /// let febrero: RawFrame = "ventas,tienda,fecha\n20,norte,2020-02-01\n".parse().unwrap();
     /// 
-    /// let datos = get_data();
+    /// enero.concat_by_name(febrero, ConcatMode::Union).unwrap();
     /// 
-    /// assert_eq!(datos.col_index("col_b"),Some(1));
+    /// assert_eq!(enero.columns.iter().collect::<Vec<&str>>(), vec!["fecha","ventas","tienda"]);
+    /// assert_eq!(enero.records[0].iter().collect::<Vec<&str>>(), vec!["2020-01-01","10",""]);
+    /// assert_eq!(enero.records[1].iter().collect::<Vec<&str>>(), vec!["2020-02-01","20","norte"]);
     /// ```
-    pub fn col_index(&self, column: &str) -> Option<usize> {
-        let cadena = String::from(column);
-        self.columns.iter().position(|col| col == cadena)
-    }
-
-    /// Returns the position index for column in RawFrame or Error if column does not exists.
-    fn col_position(&self, column: &str) -> Result<usize,Box<dyn Error>> {
-        match self.col_index(column) {
-            Some(n) => Ok(n),
-            None => Err(From::from("No existe la columna"))
+    pub fn concat_by_name(&mut self, cola: crate::RawFrame, mode: ConcatMode) -> Result<(), RavenError> {
+
+        check_unique(self.columns.iter())?;
+        check_unique(cola.columns.iter())?;
+
+        let missing: Vec<String> = self.columns.iter().filter(|col| cola.col_index(col).is_none()).map(String::from).collect();
+        let extra: Vec<String> = cola.columns.iter().filter(|col| self.col_index(col).is_none()).map(String::from).collect();
+
+        match mode {
+            ConcatMode::Strict if !missing.is_empty() || !extra.is_empty() => {
+                return Err(RavenError::ColumnSetMismatch { missing, extra })
+            },
+            ConcatMode::Strict => {},
+            ConcatMode::Intersection => {
+                let comunes: Vec<&str> = self.columns.iter().filter(|col| cola.col_index(col).is_some()).collect();
+                *self = self.select(&comunes)?;
+            },
+            ConcatMode::Union => {
+                for col in &extra {
+                    self.columns.push_field(col);
+                }
+                let ancho = self.columns.len();
+                for record in self.records.iter_mut().filter(|record| record.len() < ancho) {
+                    while record.len() < ancho {
+                        record.push_field("");
+                    }
+                }
+            },
         }
+
+        let positions: Vec<Option<usize>> = self.columns.iter().map(|col| cola.col_index(col)).collect();
+
+        self.records.extend(cola.records.iter().map(|record| {
+            let mut nuevo: csv::StringRecord = positions.iter()
+                .map(|pos| pos.and_then(|n| record.get(n)).unwrap_or(""))
+                .collect();
+            nuevo.set_position(record.position().cloned());
+            nuevo
+        }));
+
+        Ok(())
     }
 
-    /// Returns a full column of Datum. 
-    /// The column is in a consumible iterator. Each element has Datum type. All the valid rows are included.
-    /// The Datum type mixes several posibilities of types, this generates a general column.
-    /// For simple operations or plotting use specific type columns.
+    /// Validates each record with a declared schema. It returns a new RawFrame with the rows and values kept by the validation mode and a report with all the violations.
     /// 
     /// # Arguments
     ///
-    /// * `column` - A string slice that holds the name of the column
+    /// * `schema` - A DeclaredSchema with the type, nullability and rules of the columns to validate
+    /// * `mode` - A ValidationMode to reject the rows with violations, to replace the invalid values by null values or to abort
     ///
     /// # Examples
     ///
     /// ```
     /// use ravencol::RawFrame;
-    /// use ravencol::Datum;
+    /// use ravencol::dataframe::DataType;
+    /// use ravencol::validation::{ColumnSpec, DeclaredSchema, ValidationMode};
     /// use std::ffi::OsString;
     ///
     /// fn get_data() -> ravencol::RawFrame {
     ///     let path = OsString::from("./datos_test/test.csv");
     ///     let datos = RawFrame::from_os_string(path).unwrap();
     ///     datos
-    /// }
+    /// } 
     /// 
     /// let datos = get_data();
     /// 
-    /// let col: Vec<Datum> = datos.column("col_a").unwrap().collect();
+    /// let schema = DeclaredSchema::new().column(ColumnSpec::new("col_b", DataType::Float));
+    /// let (validos, report) = datos.validate(&schema, ValidationMode::Reject).unwrap();
+    /// 
+    /// assert_eq!(report.rejected_rows, 1);
     /// ```
-    pub fn column(&self, column: &str) -> Result<impl Iterator<Item=Datum> + '_,Box<dyn Error>>{
-    
-        let position = self.col_position(column)?;
-
-        Ok(self.records.iter().map(move |record| {
-            match record.get(position) {
-                None => Datum::None,
-                Some(cadena) => match cadena.parse::<i32>() {
-                    Ok(num) => Datum::Integer(num),
-                    _ => match cadena.parse::<f64>() {
-                        Ok(num) => Datum::Float(num),
-                        _ => Datum::NotNumber(cadena)
-                    },
-                }
-            }
-        }))
+    pub fn validate(&self, schema: &validation::DeclaredSchema, mode: validation::ValidationMode) -> Result<(crate::RawFrame,validation::ValidationReport), RavenError> {
+        validation::validate(self, schema, mode)
     }
 
-    /// Returns a full column of a generic type. 
-    /// The column is in a consumible iterator. Each element has Option<T> type. All the valid rows are included.
-    /// The generic type is specified in the definition of the variable in which the iterator will bind.
-    /// This method feels repetitive with methods that returns specific type columns because was created after the definition of those.
-    /// In the future the specific type columns will dissapear.
+    /// Returns a new RawFrame with the whole records for which the predicate returns true.
+    /// All the columns are kept, so the column accessors of the result stay aligned by row.
+    /// 
+    /// # Arguments
+    ///
+    /// * `predicate` - A closure which receives each Row and returns true for the rows to keep
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "estado,poblacion\nCDMX,9209944\nColima,731391\nJalisco,8348151\n".parse().unwrap();
+    /// 
+    /// let grandes = datos.filter(|row| row.parse::<i64>("poblacion").map_or(false, |pob| pob > 1000000));
+    /// 
+    /// let estados: Vec<String> = grandes.col_fil("estado").unwrap().collect();
+    /// assert_eq!(estados, vec!["CDMX", "Jalisco"]);
+    /// ```
+    pub fn filter<F>(&self, mut predicate: F) -> RawFrame
+    where F: FnMut(&Row) -> bool
+    {
+        let records = self.records.iter()
+            .filter(|record| predicate(&Row { columns: &self.columns, record }))
+            .cloned()
+            .collect();
+
+        RawFrame { columns: self.columns.clone(), records }
+    }
+
+    /// Returns a new RawFrame with the whole records for which the predicate over the value of a column returns true.
+    /// The predicate receives the value parsed to a generic type, or None if it is not possible to parse it.
     /// 
     /// # Arguments
     ///
     /// * `column` - A string slice that holds the name of the column
+    /// * `predicate` - A closure which receives the parsed value of each row and returns true for the rows to keep
     ///
     /// # Examples
     ///
     /// ```
     /// use ravencol::RawFrame;
-    /// use ravencol::Datum;
     /// use std::ffi::OsString;
     ///
     /// fn get_data() -> ravencol::RawFrame {
     ///     let path = OsString::from("./datos_test/test.csv");
     ///     let datos = RawFrame::from_os_string(path).unwrap();
     ///     datos
-    /// }
+    /// } 
     /// 
     /// let datos = get_data();
     /// 
-    /// let col: Vec<Option<i32>> = datos.col_type("col_a").unwrap().collect();
+    /// let validos = datos.filter_by("col_b", |val: Option<f64>| val.is_some()).unwrap();
+    /// 
+    /// let pares: Vec<(f64,f64)> = validos.pair_col_imp("col_a","col_b",0.0,0.0).unwrap().collect();
+    /// assert_eq!(pares, vec![(6.5,18.0),(13.0,23.0),(25.0,3.0)]);
     /// ```
-    pub fn col_type<T>(&self, column: &str) -> Result<impl Iterator<Item=Option<T>> + '_,Box<dyn Error>>
-    where T: std::str::FromStr
+    pub fn filter_by<T, F>(&self, column: &str, mut predicate: F) -> Result<RawFrame,RavenError>
+    where T: std::str::FromStr, F: FnMut(Option<T>) -> bool
     {
-
         let position = self.col_position(column)?;
 
-        Ok(self.records.iter().map(move |record| {
-            match record.get(position) {
-                None => None,
-                Some(cadena) => match cadena.parse::<T>() {
-                    Ok(num) => Some(num),
-                    _ => None
-                } 
-            }
-        }))
+        let records = self.records.iter()
+            .filter(|record| predicate(record.get(position).and_then(|cadena| cadena.parse::<T>().ok())))
+            .cloned()
+            .collect();
 
+        Ok(RawFrame { columns: self.columns.clone(), records })
     }
 
-    /// Returns a filtered column of generic type filtering for only the possible to parse data. 
-    /// The column is in a consumible iterator. Each element has T type. Only the valid parsed rows are included.
-    /// The generic type is specified in the definition of the variable in which the iterator will bind.
-    /// This method has a variable number of elements related to the rows in the RawDataframe, use it with caution.
-    /// This method feels repetitive with methods that returns specific type columns because was created after the definition of those.
-    /// In the future the specific type columns will dissapear.
+    /// Returns a new RawFrame with the whole records sorted by several keys. The sort is stable and each SortKey defines the column,
+    /// the type used to compare the values, the direction and the position of the null values. See the sort module.
+    /// 
+    /// # Arguments
+    ///
+    /// * `keys` - A slice of SortKey, the first key has the highest priority
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dataframe::DataType;
+    /// use ravencol::sort::SortKey;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let ordenado = datos.sort_by(&[SortKey::new("col_b", DataType::Float).nulls_first()]).unwrap();
+    /// 
+    /// let col: Vec<Option<f64>> = ordenado.col_type("col_b").unwrap().collect();
+    /// assert_eq!(col, vec![None, None, Some(3.0), Some(18.0), Some(23.0)]);
+    /// ```
+    pub fn sort_by(&self, keys: &[sort::SortKey]) -> Result<RawFrame,RavenError> {
+        sort::sort_by(self, keys)
+    }
+
+    /// Returns the records split in groups with the same values in the key columns, use `agg` on the result to aggregate each group.
+    /// 
+    /// # Arguments
+    ///
+    /// * `keys` - A slice of string slices with the names of the key columns
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::aggregate::Aggregation;
+    ///
+    /// let datos: RawFrame = "region,anio,ventas\nsur,2020,10\nsur,2021,5\nsur,2020,2\n".parse().unwrap();
+    /// 
+    /// let grupos = datos.group_by(&["region","anio"]).unwrap();
+    /// assert_eq!(grupos.len(), 2);
+    /// 
+    /// let resumen = grupos.agg(&[("ventas", Aggregation::Max)]).unwrap();
+    /// assert_eq!(resumen.records[0].iter().collect::<Vec<&str>>(), vec!["sur","2020","10"]);
+    /// ```
+    pub fn group_by(&self, keys: &[&str]) -> Result<groupby::GroupBy<'_>,RavenError> {
+        groupby::GroupBy::new(self, keys)
+    }
+
+    /// Returns a new RawFrame with the records of both RawFrames joined by the key columns, comparing the keys as written.
+    /// 
+    /// # Arguments
+    ///
+    /// * `other` - The right RawFrame
+    /// * `on` - A slice of string slices with the names of the key columns, they must exist in both RawFrames
+    /// * `how` - The JoinType which defines the records kept
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::join::JoinType;
+    ///
+    /// let ventas: RawFrame = "estado,ventas\nCDMX,10\nColima,5\nCDMX,3\n".parse().unwrap();
+    /// let estados: RawFrame = "estado,region\nCDMX,centro\nJalisco,occidente\n".parse().unwrap();
+    /// 
+    /// let unidas = ventas.join(&estados, &["estado"], JoinType::Inner).unwrap();
+    /// 
+    /// assert_eq!(unidas.records.len(), 2);
+    /// assert_eq!(unidas.records[1].iter().collect::<Vec<&str>>(), vec!["CDMX","3","centro"]);
+    /// 
+    /// let sin_region = ventas.join(&estados, &["estado"], JoinType::Anti).unwrap();
+    /// assert_eq!(sin_region.records[0].iter().collect::<Vec<&str>>(), vec!["Colima","5"]);
+    /// ```
+    pub fn join(&self, other: &RawFrame, on: &[&str], how: join::JoinType) -> Result<RawFrame,RavenError> {
+        join::join(self, other, on, how, &join::JoinOptions::new())
+    }
+
+    /// Returns a new RawFrame with the records of both RawFrames joined by the key columns, using the suffixes and key types of the JoinOptions.
+    /// 
+    /// # Arguments
+    ///
+    /// * `other` - The right RawFrame
+    /// * `on` - A slice of string slices with the names of the key columns, they must exist in both RawFrames
+    /// * `how` - The JoinType which defines the records kept
+    /// * `options` - The JoinOptions with the suffixes for clashing column names and the types of the keys
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::join::{JoinOptions, JoinType};
+    ///
+    /// let actual: RawFrame = "id,valor\n1,10\n2,20\n".parse().unwrap();
+    /// let anterior: RawFrame = "id,valor\n2,15\n3,30\n".parse().unwrap();
+    /// 
+    /// let opciones = JoinOptions::new().suffixes("_2021","_2020");
+    /// let unidas = actual.join_with(&anterior, &["id"], JoinType::Outer, &opciones).unwrap();
+    /// 
+    /// assert_eq!(unidas.columns.iter().collect::<Vec<&str>>(), vec!["id","valor_2021","valor_2020"]);
+    /// assert_eq!(unidas.records[2].iter().collect::<Vec<&str>>(), vec!["3","","30"]);
+    /// ```
+    pub fn join_with(&self, other: &RawFrame, on: &[&str], how: join::JoinType, options: &join::JoinOptions) -> Result<RawFrame,RavenError> {
+        join::join(self, other, on, how, options)
+    }
+
+    /// Returns a new long RawFrame with one record for each record and value column, with the id columns, the name of the value column and its value.
+    /// The records of the first value column go first.
+    /// 
+    /// # Arguments
+    ///
+    /// * `id_cols` - A slice of string slices with the names of the columns repeated in each record
+    /// * `value_cols` - A slice of string slices with the names of the value columns, if it is empty all the columns which are not id columns are used
+    /// * `var_name` - A string slice with the name of the column for the names of the value columns
+    /// * `value_name` - A string slice with the name of the column for the values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "estado,clave,2019,2020\nColima,06,5,7\n".parse().unwrap();
+    /// 
+    /// let largo = datos.melt(&["estado"], &["2020","2019"], "anio", "poblacion").unwrap();
+    /// 
+    /// assert_eq!(largo.records[0].iter().collect::<Vec<&str>>(), vec!["Colima","2020","7"]);
+    /// assert_eq!(largo.records[1].iter().collect::<Vec<&str>>(), vec!["Colima","2019","5"]);
+    /// ```
+    pub fn melt(&self, id_cols: &[&str], value_cols: &[&str], var_name: &str, value_name: &str) -> Result<RawFrame,RavenError> {
+        reshape::melt(self, id_cols, value_cols, var_name, value_name)
+    }
+
+    /// Returns a new wide RawFrame with one record for each distinct index, the index columns and one column for each distinct value of the `columns` column.
+    /// Each cell is the aggregation of the values of the records with its index and column, an empty field if there are no records.
+    /// `First` and `Last` keep the fields as written, the other aggregations only use the values which can be parsed as f64.
+    /// 
+    /// # Arguments
+    ///
+    /// * `index` - A slice of string slices with the names of the index columns
+    /// * `columns` - A string slice with the name of the column whose values are the new column names
+    /// * `values` - A string slice with the name of the column with the values
+    /// * `agg` - The Aggregation used for the values of each cell
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::aggregate::Aggregation;
+    ///
+    /// let datos: RawFrame = "region,anio,ventas\nsur,2020,10\nsur,2020,4\nnorte,2021,3\n".parse().unwrap();
+    /// 
+    /// let ancho = datos.pivot(&["region"], "anio", "ventas", Aggregation::Sum).unwrap();
+    /// 
+    /// assert_eq!(ancho.columns.iter().collect::<Vec<&str>>(), vec!["region","2020","2021"]);
+    /// assert_eq!(ancho.records[0].iter().collect::<Vec<&str>>(), vec!["sur","14",""]);
+    /// assert_eq!(ancho.records[1].iter().collect::<Vec<&str>>(), vec!["norte","","3"]);
+    /// ```
+    pub fn pivot(&self, index: &[&str], columns: &str, values: &str, agg: aggregate::Aggregation) -> Result<RawFrame,RavenError> {
+        reshape::pivot(self, index, columns, values, agg)
+    }
+
+    /// Returns a new RawFrame with only the given columns in the given order.
+    /// 
+    /// # Arguments
+    ///
+    /// * `columns` - A slice of string slices with the names of the columns to keep
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "a,b,c\n1,2,3\n4,5,6\n".parse().unwrap();
+    /// 
+    /// let seleccion = datos.select(&["c","a"]).unwrap();
+    /// 
+    /// assert_eq!(seleccion.columns.iter().collect::<Vec<&str>>(), vec!["c","a"]);
+    /// assert_eq!(seleccion.records[1].iter().collect::<Vec<&str>>(), vec!["6","4"]);
+    /// ```
+    pub fn select(&self, columns: &[&str]) -> Result<RawFrame,RavenError> {
+        let positions = self.col_positions(columns)?;
+        check_unique(columns.iter().copied())?;
+
+        Ok(self.project(&positions))
+    }
+
+    /// Returns a new RawFrame without the given columns.
+    /// 
+    /// # Arguments
+    ///
+    /// * `columns` - A slice of string slices with the names of the columns to remove
+    pub fn drop(&self, columns: &[&str]) -> Result<RawFrame,RavenError> {
+        let quitar = self.col_positions(columns)?;
+        let positions: Vec<usize> = (0..self.columns.len()).filter(|n| !quitar.contains(n)).collect();
+
+        Ok(self.project(&positions))
+    }
+
+    /// Returns a new RawFrame with a column renamed. It returns an error if the column does not exist or the new name is already used by another column.
+    /// 
+    /// # Arguments
+    ///
+    /// * `old` - A string slice that holds the name of the column
+    /// * `new` - A string slice that holds the new name of the column
+    pub fn rename(&self, old: &str, new: &str) -> Result<RawFrame,RavenError> {
+        let position = self.col_position(old)?;
+
+        if self.col_index(new).map_or(false, |n| n != position) {
+            return Err(RavenError::DuplicateColumn { name: String::from(new) })
+        }
+
+        let columns = self.columns.iter().enumerate().map(|(n, col)| if n == position { new } else { col }).collect();
+
+        Ok(RawFrame { columns, records: self.records.clone() })
+    }
+
+    /// Returns a new RawFrame with all the columns, the given ones first in the given order and then the rest in their original order.
+    /// 
+    /// # Arguments
+    ///
+    /// * `columns` - A slice of string slices with the names of the columns to move to the start
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "a,b,c\n1,2,3\n".parse().unwrap();
+    /// 
+    /// let ordenado = datos.reorder(&["c","b"]).unwrap();
+    /// 
+    /// assert_eq!(ordenado.columns.iter().collect::<Vec<&str>>(), vec!["c","b","a"]);
+    /// ```
+    pub fn reorder(&self, columns: &[&str]) -> Result<RawFrame,RavenError> {
+        let mut positions = self.col_positions(columns)?;
+        check_unique(columns.iter().copied())?;
+
+        let resto: Vec<usize> = (0..self.columns.len()).filter(|n| !positions.contains(n)).collect();
+        positions.extend(resto);
+
+        Ok(self.project(&positions))
+    }
+
+    /// Returns a new RawFrame with the columns in the given positions. Missing fields before an existing one are empty fields.
+    fn project(&self, positions: &[usize]) -> RawFrame {
+        let columns = positions.iter().filter_map(|n| self.columns.get(*n)).collect();
+
+        let records = self.records.iter().map(|record| {
+            let ancho = positions.iter().rposition(|n| *n < record.len()).map_or(0, |ultimo| ultimo + 1);
+            let mut nuevo: csv::StringRecord = positions[..ancho].iter().map(|n| record.get(*n).unwrap_or("")).collect();
+            nuevo.set_position(record.position().cloned());
+            nuevo
+        }).collect();
+
+        RawFrame { columns, records }
+    }
+
+    /// Returns the position index for column in RawFrame or None if column does not exists.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// assert_eq!(datos.col_index("col_b"),Some(1));
+    /// ```
+    pub fn col_index(&self, column: &str) -> Option<usize> {
+        let cadena = String::from(column);
+        self.columns.iter().position(|col| col == cadena)
+    }
+
+    /// Returns the position index for column in RawFrame or Error if column does not exists.
+    fn col_position(&self, column: &str) -> Result<usize,RavenError> {
+        match self.col_index(column) {
+            Some(n) => Ok(n),
+            None => Err(RavenError::ColumnNotFound {
+                name: String::from(column),
+                available: self.columns.iter().map(String::from).collect(),
+            })
+        }
+    }
+
+    /// Returns the position indexes for several columns in RawFrame or Error listing all the columns that do not exist.
+    fn col_positions(&self, columns: &[&str]) -> Result<Vec<usize>,RavenError> {
+        let positions: Vec<Option<usize>> = columns.iter().map(|col| self.col_index(col)).collect();
+
+        if positions.iter().all(|pos| pos.is_some()) {
+            return Ok(positions.into_iter().flatten().collect())
+        }
+
+        Err(RavenError::ColumnsNotFound {
+            names: columns.iter().zip(positions.iter())
+                .filter(|(_, pos)| pos.is_none())
+                .map(|(col, _)| String::from(*col))
+                .collect(),
+            available: self.columns.iter().map(String::from).collect(),
+        })
+    }
+
+    /// Returns a full column of Datum. 
+    /// The column is in a consumible iterator. Each element has Datum type. All the valid rows are included.
+    /// The Datum type mixes several posibilities of types, this generates a general column.
+    /// For simple operations or plotting use specific type columns.
     /// 
     /// # Arguments
     ///
@@ -465,171 +1246,1132 @@ impl RawFrame {
     /// 
     /// let datos = get_data();
     /// 
-    /// let col: Vec<i32> = datos.col_fil("col_a").unwrap().collect();
+    /// let col: Vec<Datum> = datos.column("col_a").unwrap().collect();
     /// ```
-    pub fn col_fil<T>(&self, column: &str) -> Result<impl Iterator<Item=T> + '_,Box<dyn Error>>
-    where T: std::str::FromStr
-    {
-        
+    pub fn column(&self, column: &str) -> Result<impl Iterator<Item=Datum<'_>> + '_,RavenError>{
+    
         let position = self.col_position(column)?;
 
-        Ok(self.records.iter().filter_map(move |record| {
-            match record.get(position) {
-                None => None,
-                Some(cadena) => cadena.parse::<T>().ok()
-            }
-        }))
+        Ok(self.records.iter().map(move |record| Datum::detect(record.get(position))))
     }
 
-    /// Returns a full column of a generic type imputing none_val in the impossible to parse data. 
-    /// The column is in a consumible iterator. Each element has T type. All the valid rows are included.
-    /// The generic type is specified in the definition of the variable in which the iterator will bind.
-    /// This method feels repetitive with methods that returns specific type columns because was created after the definition of those.
-    /// In the future the specific type columns will dissapear.
+    /// Returns a full column of Datum reading the numbers with a NumberFormat, see `column`.
     /// 
     /// # Arguments
     ///
     /// * `column` - A string slice that holds the name of the column
+    /// * `format` - The NumberFormat of the numbers in the column
+    pub fn column_with<'a>(&'a self, column: &str, format: &'a numbers::NumberFormat) -> Result<impl Iterator<Item=Datum<'a>> + 'a,RavenError>{
+    
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().map(move |record| Datum::detect_with(record.get(position), format)))
+    }
+
+    /// Returns a Series of a generic type parsing the column only once. The values which are not possible to parse are marked as invalid in the validity bitmap of the Series.
+    /// The Series can be reused for several operations without parsing the column again.
     /// 
-    /// * `none_val` - value for imputing the impossible to parse values
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
     ///
     /// # Examples
     ///
     /// ```
     /// use ravencol::RawFrame;
-    /// use ravencol::Datum;
+    /// use ravencol::series::Series;
     /// use std::ffi::OsString;
     ///
     /// fn get_data() -> ravencol::RawFrame {
     ///     let path = OsString::from("./datos_test/test.csv");
     ///     let datos = RawFrame::from_os_string(path).unwrap();
     ///     datos
-    /// } 
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let serie: Series<i32> = datos.series("col_a").unwrap();
+    /// let suma: i32 = serie.iter_valid().sum();
+    /// let maximo = serie.iter_valid().max();
+    /// ```
+    pub fn series<T>(&self, column: &str) -> Result<series::Series<T>,RavenError>
+    where T: std::str::FromStr + Default
+    {
+        let position = self.col_position(column)?;
+
+        Ok(series::Series::parse(column, self.records.iter().map(|record| record.get(position))))
+    }
+
+    /// Returns a DataFrame with the given columns parsed once to the given types.
+    /// 
+    /// # Arguments
+    ///
+    /// * `schema` - A slice of tuples with the name of each column and its DataType
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dataframe::DataType;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let frame = datos.to_dataframe(&[("col_a", DataType::Float), ("col_b", DataType::Str)]).unwrap();
+    /// ```
+    pub fn to_dataframe(&self, schema: &[(&str, dataframe::DataType)]) -> Result<dataframe::DataFrame,RavenError> {
+        dataframe::DataFrame::from_raw(self, schema)
+    }
+
+    /// Returns a Schema with one dominant type per column, a nullability flag and the ratio of values that do not conform to the dominant type.
+    /// Empty cells and missing fields are null values. Columns with integers and floats are typed by the most frequent kind, use `infer_schema_with` to promote them to float.
+    /// 
+    /// # Arguments
+    ///
+    /// * `sample_rows` - Number of rows to inspect from the start of the RawFrame, None inspects all the rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dataframe::DataType;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let schema = datos.infer_schema(None);
+    /// 
+    /// assert_eq!(schema.get("col_a").unwrap().data_type, DataType::Int);
+    /// assert_eq!(schema.get("col_a").unwrap().invalid_ratio, 0.2);
+    /// println!("{}", schema);
+    /// ```
+    pub fn infer_schema(&self, sample_rows: Option<usize>) -> schema::Schema {
+        self.infer_schema_with(sample_rows, schema::InferMode::Strict)
+    }
+
+    /// Returns a Schema with one dominant type per column using the given InferMode to decide the type of columns with integers and floats.
+    /// 
+    /// # Arguments
+    ///
+    /// * `sample_rows` - Number of rows to inspect from the start of the RawFrame, None inspects all the rows
+    /// * `mode` - `InferMode::PromoteInts` types as float the columns with integers and floats
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dataframe::DataType;
+    /// use ravencol::schema::InferMode;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let schema = datos.infer_schema_with(None, InferMode::PromoteInts);
+    /// 
+    /// assert_eq!(schema.get("col_a").unwrap().data_type, DataType::Float);
+    /// assert_eq!(schema.get("col_a").unwrap().invalid_ratio, 0.0);
+    /// ```
+    pub fn infer_schema_with(&self, sample_rows: Option<usize>, mode: schema::InferMode) -> schema::Schema {
+        let filas = sample_rows.unwrap_or(self.records.len());
+
+        let columns = self.columns.iter().enumerate().map(|(position, nombre)| {
+            let cells = self.records.iter().take(filas).map(|record| record.get(position).filter(|cadena| !cadena.is_empty()));
+            schema::infer_column(nombre, cells, mode)
+        }).collect();
+
+        schema::Schema { columns }
+    }
+
+    /// Returns a Summary with the statistics of every column and its three most frequent values.
+    /// 
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let resumen = datos.describe();
+    /// 
+    /// assert_eq!(resumen.get("col_b").unwrap().max, Some(23.0));
+    /// println!("{}", resumen);
+    /// ```
+    pub fn describe(&self) -> describe::Summary {
+        self.describe_with(3)
+    }
+
+    /// Returns a Summary with the statistics of every column and its `top_k` most frequent values.
+    /// 
+    /// # Arguments
+    ///
+    /// * `top_k` - Number of most frequent values kept for each column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "color\nrojo\nazul\nrojo\nverde\n".parse().unwrap();
+    /// 
+    /// let resumen = datos.describe_with(1);
+    /// 
+    /// assert_eq!(resumen.get("color").unwrap().top, vec![(String::from("rojo"), 2)]);
+    /// assert_eq!(resumen.get("color").unwrap().n_unique, 3);
+    /// ```
+    pub fn describe_with(&self, top_k: usize) -> describe::Summary {
+        describe::describe(self, top_k)
+    }
+
+    /// Returns a full column of a generic type. 
+    /// The column is in a consumible iterator. Each element has Option<T> type. All the valid rows are included.
+    /// The generic type is specified in the definition of the variable in which the iterator will bind.
+    /// This method feels repetitive with methods that returns specific type columns because was created after the definition of those.
+    /// In the future the specific type columns will dissapear.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::Datum;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let col: Vec<Option<i32>> = datos.col_type("col_a").unwrap().collect();
+    /// ```
+    pub fn col_type<T>(&self, column: &str) -> Result<impl Iterator<Item=Option<T>> + '_,RavenError>
+    where T: std::str::FromStr
+    {
+
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().map(move |record| {
+            match record.get(position) {
+                None => None,
+                Some(cadena) => cadena.parse::<T>().ok()
+            }
+        }))
+
+    }
+
+    /// Returns a full column of a generic type reading the numbers with a NumberFormat, see `col_type`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `format` - The NumberFormat of the numbers in the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::numbers::NumberFormat;
+    ///
+    /// let datos = RawFrame::builder().delimiter(b';').read_str("monto\n1.234,50\n12%\n3,20\n").unwrap();
+    /// let formato = NumberFormat::european().percent(true);
+    /// 
+    /// let col: Vec<Option<f64>> = datos.col_type_with("monto", &formato).unwrap().collect();
+    /// 
+    /// assert_eq!(col, vec![Some(1234.5), Some(12.0), Some(3.2)]);
+    /// ```
+    pub fn col_type_with<'a, T>(&'a self, column: &str, format: &'a numbers::NumberFormat) -> Result<impl Iterator<Item=Option<T>> + 'a,RavenError>
+    where T: std::str::FromStr
+    {
+
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().map(move |record| record.get(position).and_then(|cadena| format.parse::<T>(cadena))))
+
+    }
+
+    /// Returns the number of valid, null and invalid values of a column for a generic type.
+    /// Null values are empty fields (including the null tokens set at load time) and missing fields, invalid values are the ones which are not possible to parse to T.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos = RawFrame::builder().null_values(&["NA"]).read_str("a\n1\nNA\n25a\n3\n").unwrap();
+    /// 
+    /// let counts = datos.col_counts::<i32>("a").unwrap();
+    /// 
+    /// assert_eq!((counts.valid, counts.null, counts.invalid), (2, 1, 1));
+    /// ```
+    pub fn col_counts<T>(&self, column: &str) -> Result<ColumnCounts,RavenError>
+    where T: std::str::FromStr
+    {
+        let position = self.col_position(column)?;
+
+        let mut counts = ColumnCounts::default();
+        for record in &self.records {
+            match record.get(position) {
+                None | Some("") => counts.null += 1,
+                Some(cadena) if cadena.parse::<T>().is_ok() => counts.valid += 1,
+                Some(_) => counts.invalid += 1,
+            }
+        }
+
+        Ok(counts)
+    }
+
+    /// Returns a filtered column of generic type filtering for only the possible to parse data. 
+    /// The column is in a consumible iterator. Each element has T type. Only the valid parsed rows are included.
+    /// The generic type is specified in the definition of the variable in which the iterator will bind.
+    /// This method has a variable number of elements related to the rows in the RawDataframe, use it with caution.
+    /// This method feels repetitive with methods that returns specific type columns because was created after the definition of those.
+    /// In the future the specific type columns will dissapear.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::Datum;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let col: Vec<i32> = datos.col_fil("col_a").unwrap().collect();
+    /// ```
+    pub fn col_fil<T>(&self, column: &str) -> Result<impl Iterator<Item=T> + '_,RavenError>
+    where T: std::str::FromStr
+    {
+        
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().filter_map(move |record| {
+            match record.get(position) {
+                None => None,
+                Some(cadena) => cadena.parse::<T>().ok()
+            }
+        }))
+    }
+
+    /// Returns a filtered column of generic type reading the numbers with a NumberFormat, see `col_fil`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `format` - The NumberFormat of the numbers in the column
+    pub fn col_fil_with<'a, T>(&'a self, column: &str, format: &'a numbers::NumberFormat) -> Result<impl Iterator<Item=T> + 'a,RavenError>
+    where T: std::str::FromStr
+    {
+        
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().filter_map(move |record| record.get(position).and_then(|cadena| format.parse::<T>(cadena))))
+    }
+
+    /// Returns a full column of a generic type imputing none_val in the impossible to parse data. 
+    /// The column is in a consumible iterator. Each element has T type. All the valid rows are included.
+    /// The generic type is specified in the definition of the variable in which the iterator will bind.
+    /// This method feels repetitive with methods that returns specific type columns because was created after the definition of those.
+    /// In the future the specific type columns will dissapear.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// 
+    /// * `none_val` - value for imputing the impossible to parse values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::Datum;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let col: Vec<i32> = datos.col_imp("col_a",0).unwrap().collect();
+    /// ```
+    pub fn col_imp<T>(&self, column: &str, none_val:T) -> Result<impl Iterator<Item=T> + '_,RavenError>
+    where T: std::str::FromStr + Clone + 'static
+    {
+
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().map(move |record| {
+            match record.get(position) {
+                None => none_val.clone(),
+                Some(cadena) => match cadena.parse::<T>() {
+                    Ok(num) => num,
+                    _ => none_val.clone()
+                }
+            }
+        })) 
+
+    }
+
+    /// Returns a full column of generic type reading the numbers with a NumberFormat and imputing none_val in the values which are not possible to parse, see `col_imp`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - value for imputing the invalid values
+    /// * `format` - The NumberFormat of the numbers in the column
+    pub fn col_imp_with<'a, T>(&'a self, column: &str, none_val:T, format: &'a numbers::NumberFormat) -> Result<impl Iterator<Item=T> + 'a,RavenError>
+    where T: std::str::FromStr + Clone + 'static
+    {
+
+        let position = self.col_position(column)?;
+
+        Ok(self.records.iter().map(move |record| {
+            record.get(position).and_then(|cadena| format.parse::<T>(cadena)).unwrap_or_else(|| none_val.clone())
+        })) 
+
+    }
+
+    /// Returns the maximum value of a column. The type is generic for comparable types, in order to compare floats is necessary to define std::cmp::Ord or use ordered-float crate or similar
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::Datum;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let maximo: i32 = datos.max_num_fil("col_a").unwrap();
+    /// ```
+    pub fn max_num_fil<T>(&self, column: &str) -> Result<T,RavenError>
+    where T: std::str::FromStr + std::cmp::Ord
+    {
+
+        let iter = self.col_fil(column)?;
+
+        match iter.max() {
+            None => Err(RavenError::EmptyColumn { column: String::from(column) }),
+            Some(val) => Ok(val)
+        }
+
+    }
+
+    /// Returns the minimum value of a column. The type is generic for comparable types, in order to compare floats is necessary to define std::cmp::Ord or use ordered-float crate or similar
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::Datum;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let minimo: i32 = datos.min_num_fil("col_a").unwrap();
+    /// ```
+    pub fn min_num_fil<T>(&self, column: &str) -> Result<T,RavenError>
+    where T: std::str::FromStr + std::cmp::Ord
+    {
+
+        let iter = self.col_fil(column)?;
+
+        match iter.min() {
+            None => Err(RavenError::EmptyColumn { column: String::from(column) }),
+            Some(val) => Ok(val)
+        }
+
+    }
+
+    /// Returns the extent of range of a column. A tuple with minimum and maximum. The type is generic for comparable types, in order to compare floats is necessary to define std::cmp::Ord or use ordered-float crate or similar
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::Datum;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let extent: (i32,i32) = datos.extent_num_fil("col_a").unwrap();
+    /// ```
+    pub fn extent_num_fil<T>(&self, column: &str) -> Result<(T,T),RavenError>
+    where T: std::str::FromStr + std::cmp::Ord
+    {
+        let maximo = self.max_num_fil(column)?;
+        let minimo = self.min_num_fil(column)?;
+
+        Ok((minimo,maximo))
+    }
+
+    /// Returns the maximum value of a column of floats. `f32` and `f64` columns can be used directly, `NaN` values are handled with the given policy.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `policy` - A NanPolicy to ignore `NaN` values, to consider them the smallest or the greatest values or to return an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::floats::NanPolicy;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let maximo: f64 = datos.max_float_fil("col_a", NanPolicy::Ignore).unwrap();
+    /// assert_eq!(maximo, 25.0);
+    /// ```
+    pub fn max_float_fil<T>(&self, column: &str, policy: floats::NanPolicy) -> Result<T,RavenError>
+    where T: floats::Float
+    {
+        let (_,maximo) = self.extent_float_fil(column, policy)?;
+
+        Ok(maximo)
+    }
+
+    /// Returns the minimum value of a column of floats. `f32` and `f64` columns can be used directly, `NaN` values are handled with the given policy.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `policy` - A NanPolicy to ignore `NaN` values, to consider them the smallest or the greatest values or to return an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::floats::NanPolicy;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let minimo: f64 = datos.min_float_fil("col_a", NanPolicy::Ignore).unwrap();
+    /// assert_eq!(minimo, 6.5);
+    /// ```
+    pub fn min_float_fil<T>(&self, column: &str, policy: floats::NanPolicy) -> Result<T,RavenError>
+    where T: floats::Float
+    {
+        let (minimo,_) = self.extent_float_fil(column, policy)?;
+
+        Ok(minimo)
+    }
+
+    /// Returns the extent of range of a column of floats. A tuple with minimum and maximum computed parsing the column only once.
+    /// `f32` and `f64` columns can be used directly, `NaN` values are handled with the given policy.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `policy` - A NanPolicy to ignore `NaN` values, to consider them the smallest or the greatest values or to return an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::floats::NanPolicy;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let extent: (f64,f64) = datos.extent_float_fil("col_a", NanPolicy::Ignore).unwrap();
+    /// assert_eq!(extent, (6.5, 25.0));
+    /// ```
+    pub fn extent_float_fil<T>(&self, column: &str, policy: floats::NanPolicy) -> Result<(T,T),RavenError>
+    where T: floats::Float
+    {
+        let valores = match floats::apply_policy(self.col_fil(column)?, policy) {
+            Some(valores) => valores,
+            None => return Err(RavenError::NanFound { column: String::from(column) }),
+        };
+
+        match floats::extent(&valores, policy) {
+            None => Err(RavenError::EmptyColumn { column: String::from(column) }),
+            Some(extent) => Ok(extent)
+        }
+    }
+
+    /// Returns the arithmetic mean of a column leaving out the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.mean_fil::<i32>("x").unwrap(), 2.5);
+    /// ```
+    pub fn mean_fil<T>(&self, column: &str) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        stats::Stats::mean(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the arithmetic mean of a column imputing the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.mean_imp("x", 0).unwrap(), 2.0);
+    /// ```
+    pub fn mean_imp<T>(&self, column: &str, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        stats::Stats::mean(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the sample variance of a column leaving out the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert!((datos.variance_fil::<f64>("x").unwrap() - 3.0).abs() < 1e-12);
+    /// ```
+    pub fn variance_fil<T>(&self, column: &str) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        stats::Stats::variance(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the sample variance of a column imputing the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.variance_imp("x", 0.0).unwrap(), 3.5);
+    /// ```
+    pub fn variance_imp<T>(&self, column: &str, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        stats::Stats::variance(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the sample standard deviation of a column leaving out the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert!((datos.std_fil::<f64>("x").unwrap() - 3.0f64.sqrt()).abs() < 1e-12);
+    /// ```
+    pub fn std_fil<T>(&self, column: &str) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        stats::Stats::std(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the sample standard deviation of a column imputing the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.std_imp("x", 0.0).unwrap(), 3.5f64.sqrt());
+    /// ```
+    pub fn std_imp<T>(&self, column: &str, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        stats::Stats::std(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the median of a column leaving out the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.median_fil::<i32>("x").unwrap(), 2.0);
+    /// ```
+    pub fn median_fil<T>(&self, column: &str) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        stats::Stats::median(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the median of a column imputing the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.median_imp("x", 9).unwrap(), 2.0);
+    /// ```
+    pub fn median_imp<T>(&self, column: &str, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        stats::Stats::median(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the quantile `q` of a column leaving out the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `q` - The quantile, it must be between 0 and 1
+    /// * `method` - The QuantileMethod used when the quantile falls between two values
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::stats::QuantileMethod;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.quantile_fil::<i32>("x", 0.25, QuantileMethod::Linear).unwrap(), 1.75);
+    /// assert!(datos.quantile_fil::<i32>("x", 1.5, QuantileMethod::Linear).is_err());
+    /// ```
+    pub fn quantile_fil<T>(&self, column: &str, q: f64, method: stats::QuantileMethod) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(RavenError::InvalidQuantile { q })
+        }
+
+        let iter = self.col_fil::<T>(column)?;
+
+        stats::Stats::quantile(iter, q, method).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the quantile `q` of a column imputing the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `q` - The quantile, it must be between 0 and 1
+    /// * `method` - The QuantileMethod used when the quantile falls between two values
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::stats::QuantileMethod;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.quantile_imp("x", 0.25, QuantileMethod::Lower, 0).unwrap(), 1.0);
+    /// ```
+    pub fn quantile_imp<T>(&self, column: &str, q: f64, method: stats::QuantileMethod, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        if !(0.0..=1.0).contains(&q) {
+            return Err(RavenError::InvalidQuantile { q })
+        }
+
+        let iter = self.col_imp(column, none_val)?;
+
+        stats::Stats::quantile(iter, q, method).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the most frequent value, the smallest one on ties, of a column leaving out the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.mode_fil::<i32>("x").unwrap(), 2);
+    /// ```
+    pub fn mode_fil<T>(&self, column: &str) -> Result<T,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        stats::Stats::mode(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the most frequent value, the smallest one on ties, of a column imputing the values that are not possible to parse.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.mode_imp("x", 5).unwrap(), 2);
+    /// ```
+    pub fn mode_imp<T>(&self, column: &str, none_val: T) -> Result<T,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        stats::Stats::mode(iter).ok_or_else(|| RavenError::EmptyColumn { column: String::from(column) })
+    }
+
+    /// Returns the sum of a column leaving out the values that are not possible to parse. The sum is computed as f64, so it does not overflow.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.sum_fil::<i32>("x").unwrap(), 10.0);
+    /// ```
+    pub fn sum_fil<T>(&self, column: &str) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        Ok(iter.map(stats::Number::to_f64).filter(|valor| !valor.is_nan()).sum())
+    }
+
+    /// Returns the sum of a column imputing the values that are not possible to parse. The sum is computed as f64, so it does not overflow.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.sum_imp("x", 10).unwrap(), 20.0);
+    /// ```
+    pub fn sum_imp<T>(&self, column: &str, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        Ok(iter.map(stats::Number::to_f64).filter(|valor| !valor.is_nan()).sum())
+    }
+
+    /// Returns the product of a column leaving out the values that are not possible to parse. The product is computed as f64, so it does not overflow.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.product_fil::<i32>("x").unwrap(), 20.0);
+    /// ```
+    pub fn product_fil<T>(&self, column: &str) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number
+    {
+        let iter = self.col_fil::<T>(column)?;
+
+        Ok(iter.map(stats::Number::to_f64).filter(|valor| !valor.is_nan()).product())
+    }
+
+    /// Returns the product of a column imputing the values that are not possible to parse. The product is computed as f64, so it does not overflow.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `none_val` - The value to impute
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+    /// 
+    /// assert_eq!(datos.product_imp("x", 0).unwrap(), 0.0);
+    /// ```
+    pub fn product_imp<T>(&self, column: &str, none_val: T) -> Result<f64,RavenError>
+    where T: std::str::FromStr + stats::Number + 'static
+    {
+        let iter = self.col_imp(column, none_val)?;
+
+        Ok(iter.map(stats::Number::to_f64).filter(|valor| !valor.is_nan()).product())
+    }
+
+    /// Returns the correlation matrix of the columns using all the records where both values of each pair exist.
+    /// 
+    /// # Arguments
+    ///
+    /// * `columns` - A slice of string slices with the names of the columns, if it is empty all the numeric columns are used
+    /// * `method` - The CorrMethod, Pearson or Spearman
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::correlation::CorrMethod;
+    ///
+    /// let datos: RawFrame = "x,y\n1,10\n2,20\n3,\n4,40\n".parse().unwrap();
     /// 
-    /// let datos = get_data();
+    /// let matriz = datos.corr(&[], CorrMethod::Pearson).unwrap();
     /// 
-    /// let col: Vec<i32> = datos.col_imp("col_a",0).unwrap().collect();
+    /// assert_eq!(matriz.labels, vec!["x","y"]);
+    /// assert_eq!(matriz.get("x","y"), Some(1.0));
     /// ```
-    pub fn col_imp<T>(&self, column: &str, none_val:T) -> Result<impl Iterator<Item=T> + '_,Box<dyn Error>>
-    where T: std::str::FromStr + Clone + 'static
-    {
-
-        let position = self.col_position(column)?;
-
-        Ok(self.records.iter().map(move |record| {
-            match record.get(position) {
-                None => none_val.clone(),
-                Some(cadena) => match cadena.parse::<T>() {
-                    Ok(num) => num,
-                    _ => none_val.clone()
-                }
-            }
-        })) 
-
+    pub fn corr(&self, columns: &[&str], method: correlation::CorrMethod) -> Result<correlation::Matrix,RavenError> {
+        correlation::corr(self, columns, method, correlation::MissingPolicy::Pairwise)
     }
 
-    /// Returns the maximum value of a column. The type is generic for comparable types, in order to compare floats is necessary to define std::cmp::Ord or use ordered-float crate or similar
+    /// Returns the correlation matrix of the columns handling the missing values with the given policy.
     /// 
     /// # Arguments
     ///
-    /// * `column` - A string slice that holds the name of the column
+    /// * `columns` - A slice of string slices with the names of the columns, if it is empty all the numeric columns are used
+    /// * `method` - The CorrMethod, Pearson or Spearman
+    /// * `missing` - The MissingPolicy, Pairwise or Listwise
     ///
     /// # Examples
     ///
     /// ```
     /// use ravencol::RawFrame;
-    /// use ravencol::Datum;
-    /// use std::ffi::OsString;
+    /// use ravencol::correlation::{CorrMethod, MissingPolicy};
     ///
-    /// fn get_data() -> ravencol::RawFrame {
-    ///     let path = OsString::from("./datos_test/test.csv");
-    ///     let datos = RawFrame::from_os_string(path).unwrap();
-    ///     datos
-    /// } 
+    /// let datos: RawFrame = "x,y,z\n1,1,1\n2,2,\n3,2,3\n".parse().unwrap();
     /// 
-    /// let datos = get_data();
+    /// let matriz = datos.corr_with(&["x","y","z"], CorrMethod::Pearson, MissingPolicy::Listwise).unwrap();
     /// 
-    /// let maximo: i32 = datos.max_num_fil("col_a").unwrap();
+    /// assert_eq!(matriz.get("x","y"), Some(1.0));
     /// ```
-    pub fn max_num_fil<T>(&self, column: &str) -> Result<T,Box<dyn Error>>
-    where T: std::str::FromStr + std::cmp::Ord
-    {
-
-        let iter = self.col_fil(column)?;
-
-        match iter.max() {
-            None => Err(From::from("No se encontró el máximo")),
-            Some(val) => Ok(val)
-        }
-
+    pub fn corr_with(&self, columns: &[&str], method: correlation::CorrMethod, missing: correlation::MissingPolicy) -> Result<correlation::Matrix,RavenError> {
+        correlation::corr(self, columns, method, missing)
     }
 
-    /// Returns the minimum value of a column. The type is generic for comparable types, in order to compare floats is necessary to define std::cmp::Ord or use ordered-float crate or similar
+    /// Returns the sample covariance matrix of the columns using all the records where both values of each pair exist.
     /// 
     /// # Arguments
     ///
-    /// * `column` - A string slice that holds the name of the column
+    /// * `columns` - A slice of string slices with the names of the columns, if it is empty all the numeric columns are used
     ///
     /// # Examples
     ///
     /// ```
     /// use ravencol::RawFrame;
-    /// use ravencol::Datum;
-    /// use std::ffi::OsString;
     ///
-    /// fn get_data() -> ravencol::RawFrame {
-    ///     let path = OsString::from("./datos_test/test.csv");
-    ///     let datos = RawFrame::from_os_string(path).unwrap();
-    ///     datos
-    /// } 
+    /// let datos: RawFrame = "x,y\n1,6\n2,4\n3,2\n".parse().unwrap();
     /// 
-    /// let datos = get_data();
+    /// let matriz = datos.cov(&["x","y"]).unwrap();
     /// 
-    /// let minimo: i32 = datos.min_num_fil("col_a").unwrap();
+    /// assert_eq!(matriz.get("x","x"), Some(1.0));
+    /// assert_eq!(matriz.get("x","y"), Some(-2.0));
+    /// println!("{}", matriz);
     /// ```
-    pub fn min_num_fil<T>(&self, column: &str) -> Result<T,Box<dyn Error>>
-    where T: std::str::FromStr + std::cmp::Ord
-    {
-
-        let iter = self.col_fil(column)?;
-
-        match iter.min() {
-            None => Err(From::from("No se encontró el mínimo")),
-            Some(val) => Ok(val)
-        }
-
+    pub fn cov(&self, columns: &[&str]) -> Result<correlation::Matrix,RavenError> {
+        correlation::cov(self, columns, correlation::MissingPolicy::Pairwise)
     }
 
-    /// Returns the extent of range of a column. A tuple with minimum and maximum. The type is generic for comparable types, in order to compare floats is necessary to define std::cmp::Ord or use ordered-float crate or similar
+    /// Returns the sample covariance matrix of the columns handling the missing values with the given policy.
     /// 
     /// # Arguments
     ///
-    /// * `column` - A string slice that holds the name of the column
+    /// * `columns` - A slice of string slices with the names of the columns, if it is empty all the numeric columns are used
+    /// * `missing` - The MissingPolicy, Pairwise or Listwise
     ///
     /// # Examples
     ///
     /// ```
     /// use ravencol::RawFrame;
-    /// use ravencol::Datum;
-    /// use std::ffi::OsString;
+    /// use ravencol::correlation::MissingPolicy;
     ///
-    /// fn get_data() -> ravencol::RawFrame {
-    ///     let path = OsString::from("./datos_test/test.csv");
-    ///     let datos = RawFrame::from_os_string(path).unwrap();
-    ///     datos
-    /// } 
+    /// let datos: RawFrame = "x,y\n1,6\n2,\n3,2\n".parse().unwrap();
     /// 
-    /// let datos = get_data();
+    /// let pairwise = datos.cov(&["x","y"]).unwrap();
+    /// let listwise = datos.cov_with(&["x","y"], MissingPolicy::Listwise).unwrap();
     /// 
-    /// let extent: (i32,i32) = datos.extent_num_fil("col_a").unwrap();
+    /// assert_eq!(pairwise.get("x","x"), Some(1.0));
+    /// assert_eq!(listwise.get("x","x"), Some(2.0));
     /// ```
-    pub fn extent_num_fil<T>(&self, column: &str) -> Result<(T,T),Box<dyn Error>>
-    where T: std::str::FromStr + std::cmp::Ord
-    {
-        let maximo = self.max_num_fil(column)?;
-        let minimo = self.min_num_fil(column)?;
-
-        Ok((minimo,maximo))
+    pub fn cov_with(&self, columns: &[&str], missing: correlation::MissingPolicy) -> Result<correlation::Matrix,RavenError> {
+        correlation::cov(self, columns, missing)
     }
 
     /// Returns a pair of columns of generic type filtering for rows where both values can be parsed. 
@@ -660,29 +2402,22 @@ impl RawFrame {
     /// 
     /// let pairs: Vec<(f64,f64)> = datos.pair_col_fil("col_a","col_b").unwrap().collect();
     /// ```
-    pub fn pair_col_fil<T>(&self, xcolumn: &str, ycolumn: &str) -> Result<impl Iterator<Item=(T,T)> + '_,Box<dyn Error>>
+    pub fn pair_col_fil<T>(&self, xcolumn: &str, ycolumn: &str) -> Result<impl Iterator<Item=(T,T)> + '_,RavenError>
     where T: std::str::FromStr
     {
 
-        let xposition = self.col_position(xcolumn)?;
-
-        let yposition = self.col_position(ycolumn)?;
+        let positions = self.col_positions(&[xcolumn, ycolumn])?;
+        let (xposition, yposition) = (positions[0], positions[1]);
 
         Ok(self.records.iter().filter_map(move |record| {
             let xval = match record.get(xposition) {
                 None => None,
-                Some(cadena) => match cadena.parse::<T>() {
-                    Ok(num) => Some(num),
-                    _ => None
-                } 
+                Some(cadena) => cadena.parse::<T>().ok()
             };
 
             let yval = match record.get(yposition) {
                 None => None,
-                Some(cadena) => match cadena.parse::<T>() {
-                    Ok(num) => Some(num),
-                    _ => None
-                } 
+                Some(cadena) => cadena.parse::<T>().ok()
             };
             
             match (xval,yval) {
@@ -723,13 +2458,12 @@ impl RawFrame {
     /// 
     /// let col: Vec<i32> = datos.col_imp("col_a",0).unwrap().collect();
     /// ```
-    pub fn pair_col_imp<T>(&self, xcolumn: &str, ycolumn: &str, none_val_x:T, none_val_y:T) -> Result<impl Iterator<Item=(T,T)> + '_,Box<dyn Error>>
+    pub fn pair_col_imp<T>(&self, xcolumn: &str, ycolumn: &str, none_val_x:T, none_val_y:T) -> Result<impl Iterator<Item=(T,T)> + '_,RavenError>
     where T: std::str::FromStr + Clone + 'static
     {
 
-        let xposition = self.col_position(xcolumn)?;
-
-        let yposition = self.col_position(ycolumn)?;
+        let positions = self.col_positions(&[xcolumn, ycolumn])?;
+        let (xposition, yposition) = (positions[0], positions[1]);
 
         Ok(self.records.iter().map(move |record| {
             let xval = match record.get(xposition) {
@@ -781,11 +2515,15 @@ impl RawFrame {
     /// 
     /// let col: Vec<Vec<i32>> = datos.slice_col_imp(vec!["col_a","col_b"],vec![0,0]).unwrap().collect();
     /// ```
-    pub fn slice_col_imp<T>(&self, columns: Vec<&str>, imp_vals: Vec<T>) -> Result<impl Iterator<Item=Vec<T>> + '_,Box<dyn Error>> 
+    pub fn slice_col_imp<T>(&self, columns: Vec<&str>, imp_vals: Vec<T>) -> Result<impl Iterator<Item=Vec<T>> + '_,RavenError> 
     where T: std::str::FromStr + Clone + 'static
     {
 
-        let positions = columns.iter().map(|col| self.col_position(col).unwrap()).collect::<Vec<usize>>();
+        if columns.len() != imp_vals.len() {
+            return Err(RavenError::LengthMismatch { expected: columns.len(), found: imp_vals.len() })
+        }
+
+        let positions = self.col_positions(&columns)?;
 
         Ok(self.records.iter().map(move |record| {
             positions.iter().zip(imp_vals.iter()).map(|tup|{
@@ -826,25 +2564,22 @@ impl RawFrame {
     /// 
     /// let pairs: Vec<Vec<f64>> = datos.slice_col_fil(vec!["col_a","col_b"]).unwrap().collect();
     /// ```
-    pub fn slice_col_fil<T>(&self, columns: Vec<&str>) -> Result<impl Iterator<Item=Vec<T>> + '_,Box<dyn Error>> 
+    pub fn slice_col_fil<T>(&self, columns: Vec<&str>) -> Result<impl Iterator<Item=Vec<T>> + '_,RavenError> 
     where T: std::str::FromStr + Clone
     {
 
-        let positions = columns.iter().map(|col| self.col_position(col).unwrap()).collect::<Vec<usize>>();
+        let positions = self.col_positions(&columns)?;
 
         Ok(self.records.iter().filter_map(move |record| {
             let row = positions.iter().map(|pos|{
                 match record.get(*pos) {
                     None => None,
-                    Some(cadena) => match cadena.parse::<T>() {
-                        Ok(num) => Some(num),
-                        _ => None
-                    }
+                    Some(cadena) => cadena.parse::<T>().ok()
                 }
             }).collect::<Vec<Option<T>>>();
 
             match row.iter().all(|ele| ele.is_some()) {
-                true => Some(row.iter().map(move |val| val.as_ref().cloned().unwrap()).collect::<Vec<T>>()),
+                true => Some(row.into_iter().flatten().collect::<Vec<T>>()),
                 false=> None
             }
         }))
@@ -880,12 +2615,12 @@ impl RawFrame {
     /// 
     /// let pairs: Vec<(f64,f64)> = datos.pair_col_fil_sorted("col_a","col_b").unwrap().collect();
     /// ```
-    pub fn pair_col_fil_sorted<T>(&self, xcolumn: &str, ycolumn: &str) -> Result<impl Iterator<Item=(T,T)> + '_,Box<dyn Error>>
+    pub fn pair_col_fil_sorted<T>(&self, xcolumn: &str, ycolumn: &str) -> Result<impl Iterator<Item=(T,T)> + '_,RavenError>
     where T: std::str::FromStr + std::cmp::PartialOrd + 'static
     {
 
-        let mut temp_vec: Vec<(T,T)> = self.pair_col_fil(xcolumn, ycolumn).unwrap().collect();
-        temp_vec.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+        let mut temp_vec: Vec<(T,T)> = self.pair_col_fil(xcolumn, ycolumn)?.collect();
+        temp_vec.sort_by(|a, b| floats::partial_cmp_last(&a.0, &b.0));
 
         Ok(temp_vec.into_iter())
 
@@ -910,25 +2645,336 @@ impl RawFrame {
     /// use ravencol::Datum;
     /// use std::ffi::OsString;
     ///
-    /// fn get_data() -> ravencol::RawFrame {
-    ///     let path = OsString::from("./datos_test/test.csv");
-    ///     let datos = RawFrame::from_os_string(path).unwrap();
-    ///     datos
-    /// }
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// }
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let pairs: Vec<(f64,f64)> = datos.pair_col_imp_sorted("col_a","col_b",0.0,0.0).unwrap().collect();
+    /// ```
+    pub fn pair_col_imp_sorted<T>(&self, xcolumn: &str, ycolumn: &str, none_val_x:T, none_val_y:T) -> Result<impl Iterator<Item=(T,T)> + '_,RavenError>
+    where T: std::str::FromStr + std::cmp::PartialOrd + Copy + 'static
+    {
+
+        let mut temp_vec: Vec<(T,T)> = self.pair_col_imp(xcolumn, ycolumn, none_val_x, none_val_y)?.collect();
+        temp_vec.sort_by(|a, b| floats::partial_cmp_last(&a.0, &b.0));
+
+        Ok(temp_vec.into_iter())
+
+    }
+
+    /// Returns a pair of columns of floats sorted by values on first column. Filtering for rows where both values can be parsed.
+    /// `NaN` values on the first column are handled with the given policy, `NaN` values on the second column are kept.
+    /// The result is in a consumible iterator. Each element is a tuple of T type.
+    /// This method has a variable number of elements related to the rows in the RawDataframe, use it with caution.
+    /// This method has a different order related to the rows in the RawDataframe, use it with caution.
+    /// 
+    /// # Arguments
+    ///
+    /// * `xcolumn` - A string slice that holds the name of first the column
+    /// * `ycolumn` - A string slice that holds the name of second the column
+    /// * `policy` - A NanPolicy to drop the rows with `NaN`, to sort them first or last or to return an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::floats::NanPolicy;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let pairs: Vec<(f64,f64)> = datos.pair_col_fil_sorted_float("col_a","col_b",NanPolicy::Last).unwrap().collect();
+    /// ```
+    pub fn pair_col_fil_sorted_float<T>(&self, xcolumn: &str, ycolumn: &str, policy: floats::NanPolicy) -> Result<impl Iterator<Item=(T,T)> + '_,RavenError>
+    where T: floats::Float + 'static
+    {
+
+        let temp_vec: Vec<(T,T)> = self.pair_col_fil(xcolumn, ycolumn)?.collect();
+
+        self.sort_pairs_float(temp_vec, xcolumn, policy)
+
+    }
+
+    /// Returns a pair of columns of floats sorted by values on first column. Imputing in the impossible to parse data none_val_x for the first column and none_val_y for the second column.
+    /// `NaN` values on the first column are handled with the given policy, `NaN` values on the second column are kept.
+    /// The result is in a consumible iterator. Each element is a tuple of T type.
+    /// This method has a different order related to the rows in the RawDataframe, use it with caution.
+    /// 
+    /// # Arguments
+    ///
+    /// * `xcolumn` - A string slice that holds the name of first the column
+    /// * `ycolumn` - A string slice that holds the name of second the column
+    /// * `none_val_x` - value for imputing the impossible to parse values for the first column
+    /// * `none_val_y` - value for imputing the impossible to parse values for the second column
+    /// * `policy` - A NanPolicy to drop the rows with `NaN`, to sort them first or last or to return an error
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::floats::NanPolicy;
+    /// use std::ffi::OsString;
+    ///
+    /// fn get_data() -> ravencol::RawFrame {
+    ///     let path = OsString::from("./datos_test/test.csv");
+    ///     let datos = RawFrame::from_os_string(path).unwrap();
+    ///     datos
+    /// } 
+    /// 
+    /// let datos = get_data();
+    /// 
+    /// let pairs: Vec<(f64,f64)> = datos.pair_col_imp_sorted_float("col_a","col_b",0.0,0.0,NanPolicy::Error).unwrap().collect();
+    /// ```
+    pub fn pair_col_imp_sorted_float<T>(&self, xcolumn: &str, ycolumn: &str, none_val_x:T, none_val_y:T, policy: floats::NanPolicy) -> Result<impl Iterator<Item=(T,T)> + '_,RavenError>
+    where T: floats::Float + 'static
+    {
+
+        let temp_vec: Vec<(T,T)> = self.pair_col_imp(xcolumn, ycolumn, none_val_x, none_val_y)?.collect();
+
+        self.sort_pairs_float(temp_vec, xcolumn, policy)
+
+    }
+
+    /// Sorts pairs of floats by the first value applying the NanPolicy.
+    fn sort_pairs_float<T>(&self, mut temp_vec: Vec<(T,T)>, xcolumn: &str, policy: floats::NanPolicy) -> Result<std::vec::IntoIter<(T,T)>,RavenError>
+    where T: floats::Float
+    {
+        match policy {
+            floats::NanPolicy::Ignore => temp_vec.retain(|pair| !pair.0.is_nan_value()),
+            floats::NanPolicy::Error if temp_vec.iter().any(|pair| pair.0.is_nan_value()) => {
+                return Err(RavenError::NanFound { column: String::from(xcolumn) })
+            },
+            _ => {},
+        }
+
+        temp_vec.sort_by(|a, b| floats::compare(a.0, b.0, policy));
+
+        Ok(temp_vec.into_iter())
+    }
+
+    /// Returns a full column of dates or date times read with a strftime-style format.
+    /// The column is in a consumible iterator. Each element has Option<X> type where X is `Date` or `DateTime`. All the rows are included.
+    /// If the format is None it is detected from the values of the column with `DateFormat::detect`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `format` - The strftime-style format of the column or None to detect it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dates::{Date, DateTime};
+    ///
+    /// let datos: RawFrame = "fecha,hora\n15/03/2020,2020-03-15 10:30:00\n16/03/2020,\n".parse().unwrap();
+    /// 
+    /// let fechas: Vec<Option<Date>> = datos.col_date("fecha", None).unwrap().collect();
+    /// assert_eq!(fechas[1], Date::new(2020, 3, 16));
+    /// 
+    /// let horas: Vec<Option<DateTime>> = datos.col_date("hora", Some("%Y-%m-%d %H:%M:%S")).unwrap().collect();
+    /// assert_eq!(horas[0].unwrap().hour(), 10);
+    /// assert_eq!(horas[1], None);
+    /// ```
+    pub fn col_date<X>(&self, column: &str, format: Option<&str>) -> Result<impl Iterator<Item=Option<X>> + '_,RavenError>
+    where X: dates::Temporal
+    {
+        let position = self.col_position(column)?;
+        let formato = self.date_format::<X>(position, format)?;
+
+        Ok(self.records.iter().map(move |record| {
+            match (record.get(position), &formato) {
+                (Some(cadena), Some(formato)) => X::parse_with(cadena, formato),
+                _ => None,
+            }
+        }))
+    }
+
+    /// Returns a pair of columns sorted by date, the first one with dates or date times read with a strftime-style format and the second one of generic type.
+    /// Only the rows where both values can be parsed are included. This method is mainly used to plot time series.
+    /// If the format is None it is detected from the values of the first column with `DateFormat::detect`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `xcolumn` - A string slice that holds the name of the column with dates
+    /// * `ycolumn` - A string slice that holds the name of the column with values
+    /// * `format` - The strftime-style format of the dates or None to detect it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::dates::Date;
+    ///
+    /// let datos: RawFrame = "fecha,casos\n03/02/2020,5\n01/02/2020,3\n02/02/2020,x\n".parse().unwrap();
+    /// 
+    /// let serie: Vec<(Date, i32)> = datos.pair_col_fil_date("fecha", "casos", Some("%d/%m/%Y")).unwrap().collect();
+    /// 
+    /// assert_eq!(serie, vec![(Date::new(2020, 2, 1).unwrap(), 3), (Date::new(2020, 2, 3).unwrap(), 5)]);
+    /// ```
+    pub fn pair_col_fil_date<X, T>(&self, xcolumn: &str, ycolumn: &str, format: Option<&str>) -> Result<impl Iterator<Item=(X,T)> + '_,RavenError>
+    where X: dates::Temporal, T: std::str::FromStr + 'static
+    {
+        let positions = self.col_positions(&[xcolumn, ycolumn])?;
+        let (xposition, yposition) = (positions[0], positions[1]);
+        let formato = self.date_format::<X>(xposition, format)?;
+
+        let mut temp_vec: Vec<(X,T)> = match &formato {
+            None => Vec::new(),
+            Some(formato) => self.records.iter().filter_map(|record| {
+                let xval = X::parse_with(record.get(xposition)?, formato)?;
+                let yval = record.get(yposition)?.parse::<T>().ok()?;
+                Some((xval, yval))
+            }).collect(),
+        };
+        temp_vec.sort_by_key(|pair| pair.0);
+
+        Ok(temp_vec.into_iter())
+    }
+
+    /// Returns a pair of columns sorted by date, the first one with dates or date times read with a strftime-style format and the second one of generic type
+    /// imputing none_val_y in the values of the second column which are not possible to parse. The rows where the date is not possible to parse are not included.
+    /// If the format is None it is detected from the values of the first column with `DateFormat::detect`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `xcolumn` - A string slice that holds the name of the column with dates
+    /// * `ycolumn` - A string slice that holds the name of the column with values
+    /// * `format` - The strftime-style format of the dates or None to detect it
+    /// * `none_val_y` - value for imputing the impossible to parse values for the second column
+    pub fn pair_col_imp_date<X, T>(&self, xcolumn: &str, ycolumn: &str, format: Option<&str>, none_val_y: T) -> Result<impl Iterator<Item=(X,T)> + '_,RavenError>
+    where X: dates::Temporal, T: std::str::FromStr + Clone + 'static
+    {
+        let positions = self.col_positions(&[xcolumn, ycolumn])?;
+        let (xposition, yposition) = (positions[0], positions[1]);
+        let formato = self.date_format::<X>(xposition, format)?;
+
+        let mut temp_vec: Vec<(X,T)> = match &formato {
+            None => Vec::new(),
+            Some(formato) => self.records.iter().filter_map(|record| {
+                let xval = X::parse_with(record.get(xposition)?, formato)?;
+                let yval = record.get(yposition).and_then(|cadena| cadena.parse::<T>().ok()).unwrap_or_else(|| none_val_y.clone());
+                Some((xval, yval))
+            }).collect(),
+        };
+        temp_vec.sort_by_key(|pair| pair.0);
+
+        Ok(temp_vec.into_iter())
+    }
+
+    /// Returns a date column and a slice of columns of generic type sorted by date. Only the rows where all the values can be parsed are included.
+    /// If the format is None it is detected from the values of the date column with `DateFormat::detect`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `xcolumn` - A string slice that holds the name of the column with dates
+    /// * `columns` - A Vec of string slices that holds the names of the columns with values
+    /// * `format` - The strftime-style format of the dates or None to detect it
+    pub fn slice_col_fil_date<X, T>(&self, xcolumn: &str, columns: Vec<&str>, format: Option<&str>) -> Result<impl Iterator<Item=(X,Vec<T>)> + '_,RavenError>
+    where X: dates::Temporal, T: std::str::FromStr + 'static
+    {
+        let xposition = self.col_position(xcolumn)?;
+        let positions = self.col_positions(&columns)?;
+        let formato = self.date_format::<X>(xposition, format)?;
+
+        let mut temp_vec: Vec<(X,Vec<T>)> = match &formato {
+            None => Vec::new(),
+            Some(formato) => self.records.iter().filter_map(|record| {
+                let xval = X::parse_with(record.get(xposition)?, formato)?;
+                let row = positions.iter().map(|pos| record.get(*pos)?.parse::<T>().ok()).collect::<Option<Vec<T>>>()?;
+                Some((xval, row))
+            }).collect(),
+        };
+        temp_vec.sort_by_key(|pair| pair.0);
+
+        Ok(temp_vec.into_iter())
+    }
+
+    /// Returns a date column and a slice of columns of generic type sorted by date imputing in the impossible to parse data the values in the imp_vals Vec.
+    /// The rows where the date is not possible to parse are not included.
+    /// If the format is None it is detected from the values of the date column with `DateFormat::detect`.
+    /// 
+    /// # Arguments
+    ///
+    /// * `xcolumn` - A string slice that holds the name of the column with dates
+    /// * `columns` - A Vec of string slices that holds the names of the columns with values
+    /// * `imp_vals` - values for imputing the impossible to parse values, it has the same order of columns
+    /// * `format` - The strftime-style format of the dates or None to detect it
+    pub fn slice_col_imp_date<X, T>(&self, xcolumn: &str, columns: Vec<&str>, imp_vals: Vec<T>, format: Option<&str>) -> Result<impl Iterator<Item=(X,Vec<T>)> + '_,RavenError>
+    where X: dates::Temporal, T: std::str::FromStr + Clone + 'static
+    {
+        if columns.len() != imp_vals.len() {
+            return Err(RavenError::LengthMismatch { expected: columns.len(), found: imp_vals.len() })
+        }
+
+        let xposition = self.col_position(xcolumn)?;
+        let positions = self.col_positions(&columns)?;
+        let formato = self.date_format::<X>(xposition, format)?;
+
+        let mut temp_vec: Vec<(X,Vec<T>)> = match &formato {
+            None => Vec::new(),
+            Some(formato) => self.records.iter().filter_map(|record| {
+                let xval = X::parse_with(record.get(xposition)?, formato)?;
+                let row = positions.iter().zip(imp_vals.iter()).map(|(pos, imp)| {
+                    record.get(*pos).and_then(|cadena| cadena.parse::<T>().ok()).unwrap_or_else(|| imp.clone())
+                }).collect::<Vec<T>>();
+                Some((xval, row))
+            }).collect(),
+        };
+        temp_vec.sort_by_key(|pair| pair.0);
+
+        Ok(temp_vec.into_iter())
+    }
+
+    /// Returns a new RawFrame grouping the rows by calendar periods of a date column and aggregating the value columns.
+    /// The result has the date column with the first day of each period, sorted by date, and one column named `column_aggregation` for each aggregation.
+    /// The values which are not possible to parse as f64 are not taken into account and the rows where the date is not possible to parse are left out.
+    /// If the format is None it is detected from the values of the date column with `DateFormat::detect`, the values can be dates or date times.
+    /// 
+    /// # Arguments
+    ///
+    /// * `date_column` - A string slice that holds the name of the column with dates
+    /// * `format` - The strftime-style format of the dates or None to detect it
+    /// * `frequency` - The length of the periods
+    /// * `aggs` - A slice of tuples with the name of each value column and its Aggregation
+    /// * `fill` - What to do with the periods without rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use ravencol::aggregate::Aggregation;
+    /// use ravencol::resample::{Frequency, GapFill};
+    ///
+    /// let datos: RawFrame = "fecha,ventas\n2020-01-06 10:00,10\n2020-01-08 12:30,5\n2020-01-21 09:00,7\n".parse().unwrap();
     /// 
-    /// let datos = get_data();
+    /// let semanal = datos.resample("fecha", None, Frequency::Week, &[("ventas", Aggregation::Mean)], GapFill::Null).unwrap();
     /// 
-    /// let pairs: Vec<(f64,f64)> = datos.pair_col_imp_sorted("col_a","col_b",0.0,0.0).unwrap().collect();
+    /// let medias: Vec<Option<f64>> = semanal.col_type("ventas_mean").unwrap().collect();
+    /// assert_eq!(medias, vec![Some(7.5), None, Some(7.0)]);
     /// ```
-    pub fn pair_col_imp_sorted<T>(&self, xcolumn: &str, ycolumn: &str, none_val_x:T, none_val_y:T) -> Result<impl Iterator<Item=(T,T)> + '_,Box<dyn Error>>
-    where T: std::str::FromStr + std::cmp::PartialOrd + Copy + 'static
-    {
-
-        let mut temp_vec: Vec<(T,T)> = self.pair_col_imp(xcolumn, ycolumn, none_val_x, none_val_y).unwrap().collect();
-        temp_vec.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
-
-        Ok(temp_vec.into_iter())
+    pub fn resample(&self, date_column: &str, format: Option<&str>, frequency: resample::Frequency, aggs: &[(&str, aggregate::Aggregation)], fill: resample::GapFill) -> Result<RawFrame,RavenError> {
+        resample::resample(self, date_column, format, frequency, aggs, fill)
+    }
 
+    /// Returns the DateFormat given by the user or the one detected in the column, None if no format reads any value of the column.
+    fn date_format<X>(&self, position: usize, format: Option<&str>) -> Result<Option<dates::DateFormat>,RavenError>
+    where X: dates::Temporal
+    {
+        match format {
+            Some(format) => Ok(Some(dates::DateFormat::new(format)?)),
+            None => Ok(dates::DateFormat::detect::<X, _>(self.records.iter().filter_map(|record| record.get(position)))),
+        }
     }
 
     /// Returns a vec of generic type imputing in the impossible to parse data the values in the imp_vals Vec. 
@@ -958,13 +3004,18 @@ impl RawFrame {
     /// 
     /// let mcvec: Vec<f64> = datos.column_major_vector(vec!["col_a","col_b"],vec![0.0,0.0]).unwrap();
     /// ```
-    pub fn column_major_vector<T>(&self, columns: Vec<&str>, imp_vals: Vec<T>) -> Result<Vec<T>,Box<dyn Error>> 
+    pub fn column_major_vector<T>(&self, columns: Vec<&str>, imp_vals: Vec<T>) -> Result<Vec<T>,RavenError> 
         where T: std::str::FromStr + Copy + 'static
     {
+        if columns.len() != imp_vals.len() {
+            return Err(RavenError::LengthMismatch { expected: columns.len(), found: imp_vals.len() })
+        }
+
+        self.col_positions(&columns)?;
+
         let salida: Vec<Vec<T>> = columns.iter().zip(imp_vals.iter()).map(|(col,val)| {
-            let vectemp: Vec<T> = self.col_imp(col,*val).unwrap().collect();
-            vectemp
-        }).collect();
+            self.col_imp(col,*val).map(|iter| iter.collect())
+        }).collect::<Result<Vec<Vec<T>>,RavenError>>()?;
 
         Ok(salida.concat())
     }
@@ -996,7 +3047,7 @@ impl RawFrame {
     /// 
     /// let mrvec: Vec<f64> = datos.row_major_vector(vec!["col_a","col_b"],vec![0.0,0.0]).unwrap();
     /// ```
-    pub fn row_major_vector<T>(&self, columns: Vec<&str>, imp_vals: Vec<T>) -> Result<Vec<T>,Box<dyn Error>> 
+    pub fn row_major_vector<T>(&self, columns: Vec<&str>, imp_vals: Vec<T>) -> Result<Vec<T>,RavenError> 
         where T: std::str::FromStr + Copy + 'static
     {
         let salida: Vec<Vec<T>> = self.slice_col_imp(columns, imp_vals)?.collect();
@@ -1007,6 +3058,25 @@ impl RawFrame {
 
 }
 
+impl std::str::FromStr for RawFrame {
+    type Err = RavenError;
+
+    /// Creates a RawFrame from a string slice holding CSV data.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    ///
+    /// let datos: RawFrame = "col_a,col_b\n1,2\n3,4\n".parse().unwrap();
+    ///
+    /// assert_eq!(datos.columns.get(1),Some("col_b"));
+    /// ```
+    fn from_str(data: &str) -> Result<RawFrame, Self::Err> {
+        RawFrame::from_reader(data.as_bytes())
+    }
+}
+
 pub mod utils {
     //! Auxiliar module with handy methods.
 
@@ -1028,10 +3098,10 @@ pub mod writing {
     //! Auxiliar module for writing CSV files.
 
     use std::ffi::OsString;
-    use std::error::Error;
+    use crate::RavenError;
 
     /// Write a csv file from an iter. It is necessary that the elements of the iter are vecs of a defined type. In order to write an iter obtained from column producer methods of RawFrame it must be casted to a type first
-    pub fn to_csv_iter<T>(path: OsString, columns: Vec<&str>, iterador: impl Iterator<Item=Vec<T>>) -> Result<(), Box<dyn Error>>
+    pub fn to_csv_iter<T>(path: OsString, columns: Vec<&str>, iterador: impl Iterator<Item=Vec<T>>) -> Result<(), RavenError>
     where T: std::convert::AsRef<[u8]>
     {
 
@@ -1054,27 +3124,631 @@ pub mod reading {
     //! Auxiliar module for reading CSV files.
 
     use std::env;
-    use std::error::Error;
+    use crate::RavenError;
     use std::ffi::OsString;
     use std::collections::HashMap;
+    use std::path::{Path, PathBuf};
+
+    pub use csv::Trim;
+
+    /// A tuple with the column names and the rows of a csv file, each row represented with type R.
+    pub type Table<R> = (csv::StringRecord,Vec<R>);
+
+    /// What to do with a malformed row found while loading a CSV file.
+    ///
+    /// A row is malformed when it is not valid UTF-8 (unless the lossy option is used), when it has a different number of fields
+    /// than the header and the options are not flexible or when it can not be read. Reading stops at an I/O error and the rows
+    /// which can not be read have no raw fields to quarantine.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum BadRowPolicy {
+        /// Skip the row and record it in the LoadReport.
+        Skip,
+        /// Stop loading and return an error with the line of the row.
+        Fail,
+        /// Skip the row, record it in the LoadReport and keep its raw fields in the quarantine RawFrame of the report.
+        Quarantine,
+    }
+
+    /// A malformed row found while loading a CSV file.
+    #[derive(Debug, Clone, PartialEq, Eq)]
+    pub struct BadRow {
+        /// Line of the file where the row starts, beginning at 1.
+        pub line: u64,
+        /// Byte offset of the file where the row starts.
+        pub byte: u64,
+        /// Index of the row in the file counting every row, including the skipped ones and the header.
+        pub record: u64,
+        /// Description of the csv error found in the row.
+        pub error: String,
+    }
+
+    impl BadRow {
+        fn new(row: &csv::ByteRecord, error: String) -> BadRow {
+            let (line, byte, record) = match row.position() {
+                Some(pos) => (pos.line(), pos.byte(), pos.record()),
+                None => (0, 0, 0),
+            };
+
+            BadRow { line, byte, record, error }
+        }
+
+        fn from_error(err: &csv::Error) -> BadRow {
+            let (line, byte, record) = match err.position() {
+                Some(pos) => (pos.line(), pos.byte(), pos.record()),
+                None => (0, 0, 0),
+            };
+
+            BadRow { line, byte, record, error: err.to_string() }
+        }
+    }
+
+    /// Report of a CSV load. It lists the malformed rows and, with the `BadRowPolicy::Quarantine` policy, a RawFrame with their raw fields.
+    #[derive(Debug, Default)]
+    pub struct LoadReport {
+        pub bad_rows: Vec<BadRow>,
+        pub quarantine: Option<crate::RawFrame>,
+    }
+
+    impl LoadReport {
+        /// Returns true if no malformed rows were found.
+        pub fn is_clean(&self) -> bool {
+            self.bad_rows.is_empty()
+        }
+    }
+
+    /// A file which could not be loaded or concatenated when several files were loaded.
+    #[derive(Debug)]
+    pub struct FileFailure {
+        pub path: PathBuf,
+        pub error: RavenError,
+    }
+
+    /// Report of a load of several files. It lists the files loaded, in the order they were concatenated, and the files that failed.
+    #[derive(Debug, Default)]
+    pub struct FilesReport {
+        pub loaded: Vec<PathBuf>,
+        pub failed: Vec<FileFailure>,
+    }
+
+    impl FilesReport {
+        /// Returns true if all the files were loaded.
+        pub fn is_clean(&self) -> bool {
+            self.failed.is_empty()
+        }
+    }
+
+    /// Returns the paths matching a glob pattern sorted alphabetically.
+    fn expand_glob(pattern: &str) -> Result<Vec<PathBuf>, RavenError> {
+        let patron = Path::new(pattern);
+        let mut actuales: Vec<PathBuf> = vec![PathBuf::new()];
+
+        let componentes: Vec<std::path::Component> = patron.components().collect();
+        for (n, componente) in componentes.iter().enumerate() {
+            let ultimo = n + 1 == componentes.len();
+            let texto = componente.as_os_str().to_string_lossy();
+
+            if !texto.contains(['*', '?', '[']) {
+                actuales = actuales.into_iter()
+                    .map(|actual| actual.join(componente.as_os_str()))
+                    .filter(|ruta| if ultimo { ruta.is_file() } else { ruta.is_dir() })
+                    .collect();
+                continue;
+            }
+
+            let mut siguientes = Vec::new();
+            for actual in &actuales {
+                let dir = if actual.as_os_str().is_empty() { Path::new(".") } else { actual.as_path() };
+                let entradas = match std::fs::read_dir(dir) {
+                    Ok(entradas) => entradas,
+                    Err(_) => continue,
+                };
+                for entrada in entradas {
+                    let entrada = entrada?;
+                    let nombre = entrada.file_name().to_string_lossy().into_owned();
+                    let tipo = entrada.file_type()?;
+                    let adecuado = if ultimo { !tipo.is_dir() } else { !tipo.is_file() };
+                    if adecuado && !nombre.starts_with('.') && wildcard_match(&texto, &nombre) {
+                        siguientes.push(actual.join(&nombre));
+                    }
+                }
+            }
+            actuales = siguientes;
+        }
+
+        actuales.sort();
+        Ok(actuales)
+    }
+
+    /// Returns true if a name matches a pattern with `*`, `?` and `[abc]` wildcards.
+    fn wildcard_match(pattern: &str, name: &str) -> bool {
+        let patron: Vec<char> = pattern.chars().collect();
+        let nombre: Vec<char> = name.chars().collect();
+
+        // Position in the pattern after the last `*` and position in the name where that `*` started to match.
+        let (mut p, mut n) = (0, 0);
+        let mut estrella: Option<(usize, usize)> = None;
+
+        while n < nombre.len() {
+            let avance = match patron.get(p) {
+                Some('*') => {
+                    estrella = Some((p + 1, n));
+                    p += 1;
+                    continue;
+                },
+                Some('?') => Some(1),
+                Some('[') => match patron[p..].iter().position(|c| *c == ']') {
+                    Some(fin) if patron[p + 1..p + fin].contains(&nombre[n]) => Some(fin + 1),
+                    Some(_) => None,
+                    None if nombre[n] == '[' => Some(1),
+                    None => None,
+                },
+                Some(c) if *c == nombre[n] => Some(1),
+                _ => None,
+            };
+
+            match (avance, estrella) {
+                (Some(avance), _) => {
+                    p += avance;
+                    n += 1;
+                },
+                (None, Some((despues, inicio))) => {
+                    p = despues;
+                    n = inicio + 1;
+                    estrella = Some((despues, inicio + 1));
+                },
+                (None, None) => return false,
+            }
+        }
+
+        patron[p..].iter().all(|c| *c == '*')
+    }
+
+    /// Options used to parse a CSV file into a RawFrame.
+    ///
+    /// The default options are the ones used by `RawFrame::from_os_string`: comma delimiter, double quotes,
+    /// a header row, flexible rows and trimming of all the fields. A `ReadOptions` is normally obtained with `RawFrame::builder()`,
+    /// configured with the chained setters and consumed with `read_os_string`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use ravencol::RawFrame;
+    /// use std::ffi::OsString;
+    ///
+    /// let path = OsString::from("./datos_test/test.csv");
+    /// let datos = RawFrame::builder().skip_rows(1).has_headers(false).read_os_string(path).unwrap();
+    ///
+    /// assert_eq!(datos.columns.get(0),Some("col_0"));
+    /// assert_eq!(datos.records[0].get(0),Some("6.5"));
+    /// ```
+    #[derive(Debug, Clone)]
+    pub struct ReadOptions {
+        delimiter: u8,
+        quote: u8,
+        escape: Option<u8>,
+        comment: Option<u8>,
+        has_headers: bool,
+        trim: csv::Trim,
+        flexible: bool,
+        skip_rows: usize,
+        max_rows: Option<usize>,
+        bad_rows: BadRowPolicy,
+        lossy: bool,
+        null_values: Vec<String>,
+        column_null_values: Vec<(String, Vec<String>)>,
+        source_column: Option<String>,
+        concat_mode: crate::ConcatMode,
+        threads: usize,
+    }
+
+    impl Default for ReadOptions {
+        fn default() -> ReadOptions {
+            ReadOptions {
+                delimiter: b',',
+                quote: b'"',
+                escape: None,
+                comment: None,
+                has_headers: true,
+                trim: csv::Trim::All,
+                flexible: true,
+                skip_rows: 0,
+                max_rows: None,
+                bad_rows: BadRowPolicy::Skip,
+                lossy: false,
+                null_values: Vec::new(),
+                column_null_values: Vec::new(),
+                source_column: None,
+                concat_mode: crate::ConcatMode::Union,
+                threads: 0,
+            }
+        }
+    }
+
+    impl ReadOptions {
+        /// Creates a ReadOptions with the default values.
+        pub fn new() -> ReadOptions {
+            ReadOptions::default()
+        }
+
+        /// Sets the field delimiter. The default is `b','`.
+        pub fn delimiter(&mut self, delimiter: u8) -> &mut ReadOptions {
+            self.delimiter = delimiter;
+            self
+        }
+
+        /// Sets the quote character. The default is `b'"'`.
+        pub fn quote(&mut self, quote: u8) -> &mut ReadOptions {
+            self.quote = quote;
+            self
+        }
+
+        /// Sets the escape character for quotes inside quoted fields. When it is set, doubled quotes are no longer treated as escapes.
+        pub fn escape(&mut self, escape: Option<u8>) -> &mut ReadOptions {
+            self.escape = escape;
+            self
+        }
+
+        /// Sets the comment prefix. Lines starting with this character are ignored.
+        pub fn comment(&mut self, comment: Option<u8>) -> &mut ReadOptions {
+            self.comment = comment;
+            self
+        }
+
+        /// Sets if the first row (after the skipped rows) holds the names of the columns.
+        /// When there is no header the columns are named `col_0`, `col_1`, ... using their position.
+        pub fn has_headers(&mut self, has_headers: bool) -> &mut ReadOptions {
+            self.has_headers = has_headers;
+            self
+        }
+
+        /// Sets the trim policy for the header and the fields. The default is `csv::Trim::All`.
+        pub fn trim(&mut self, trim: csv::Trim) -> &mut ReadOptions {
+            self.trim = trim;
+            self
+        }
+
+        /// Sets if rows with a different number of fields are allowed. The default is `true`.
+        pub fn flexible(&mut self, flexible: bool) -> &mut ReadOptions {
+            self.flexible = flexible;
+            self
+        }
+
+        /// Sets the number of rows to skip at the start of the file, before the header.
+        pub fn skip_rows(&mut self, skip_rows: usize) -> &mut ReadOptions {
+            self.skip_rows = skip_rows;
+            self
+        }
+
+        /// Sets the maximum number of data rows to read. `None` reads the whole file.
+        pub fn max_rows(&mut self, max_rows: Option<usize>) -> &mut ReadOptions {
+            self.max_rows = max_rows;
+            self
+        }
+
+        /// Sets what to do with malformed rows. The default is `BadRowPolicy::Skip`.
+        pub fn bad_rows(&mut self, policy: BadRowPolicy) -> &mut ReadOptions {
+            self.bad_rows = policy;
+            self
+        }
+
+        /// Sets if the fields with invalid UTF-8 are decoded replacing the invalid bytes instead of treating the row as malformed. The default is `false`.
+        pub fn lossy(&mut self, lossy: bool) -> &mut ReadOptions {
+            self.lossy = lossy;
+            self
+        }
+
+        /// Sets the tokens which represent a null value in all the columns, for example `&["NA", "N/A", "-", "null"]`.
+        /// The fields equal to one of the tokens (after trimming) are loaded as empty fields, so they are null values for every accessor.
+        /// Empty fields are always null values. A RawFrame does not keep which empty fields were tokens, so `Datum::detect` returns
+        /// `Datum::Empty` for them; `Datum::None` is only returned for the fields missing from short records.
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use ravencol::RawFrame;
+        ///
+        /// let datos = RawFrame::builder().null_values(&["NA", "-"]).read_str("a,b\nNA,1\n2,-\n").unwrap();
+        ///
+        /// assert_eq!(datos.records[0].get(0), Some(""));
+        /// assert_eq!(datos.records[1].get(1), Some(""));
+        /// ```
+        pub fn null_values(&mut self, tokens: &[&str]) -> &mut ReadOptions {
+            self.null_values = tokens.iter().map(|token| String::from(*token)).collect();
+            self
+        }
+
+        /// Sets the tokens which represent a null value in one column. They replace the tokens set with `null_values` for that column.
+        /// Loading fails with `RavenError::ColumnNotFound` if the column does not exist.
+        ///
+        /// # Arguments
+        ///
+        /// * `column` - A string slice that holds the name of the column
+        /// * `tokens` - The tokens which represent a null value in the column
+        pub fn column_null_values(&mut self, column: &str, tokens: &[&str]) -> &mut ReadOptions {
+            let tokens: Vec<String> = tokens.iter().map(|token| String::from(*token)).collect();
+            match self.column_null_values.iter_mut().find(|(nombre, _)| nombre == column) {
+                Some((_, anteriores)) => *anteriores = tokens,
+                None => self.column_null_values.push((String::from(column), tokens)),
+            }
+            self
+        }
+
+        /// Sets the name of a column added when several files are loaded, it holds the path of the file of each record. None does not add the column, it is the default.
+        /// A file which already has a column with this name fails to load. Short records are padded with empty fields so the path is always in this column,
+        /// the extra fields of flexible records are kept after it.
+        pub fn source_column(&mut self, column: Option<&str>) -> &mut ReadOptions {
+            self.source_column = column.map(String::from);
+            self
+        }
+
+        /// Sets how the columns of several files are combined. The default is `ConcatMode::Union`.
+        pub fn concat_mode(&mut self, mode: crate::ConcatMode) -> &mut ReadOptions {
+            self.concat_mode = mode;
+            self
+        }
+
+        /// Sets the number of threads used to parse several files. 1 parses the files one by one and 0, the default, uses the available parallelism.
+        pub fn threads(&mut self, threads: usize) -> &mut ReadOptions {
+            self.threads = threads;
+            self
+        }
+
+        /// Creates a RawFrame concatenating all the CSV files which match a glob pattern, loaded in alphabetical order of their paths.
+        /// The pattern can use `*` (any sequence of characters), `?` (any character) and `[abc]` (one of the characters) in any component of the path.
+        /// The files that can not be loaded or concatenated are listed in the FilesReport. It returns an error if no file matches the pattern.
+        ///
+        /// # Arguments
+        ///
+        /// * `pattern` - A string slice with the glob pattern, like `data/*.csv`
+        ///
+        /// # Examples
+        ///
+        /// ```
+        /// use ravencol::RawFrame;
+        ///
+        /// let (datos, report) = RawFrame::builder().source_column(Some("source_file")).read_glob("./datos_test/test.csv").unwrap();
+        ///
+        /// assert!(report.is_clean());
+        /// assert_eq!(datos.records[0].get(2), Some("./datos_test/test.csv"));
+        /// ```
+        pub fn read_glob(&self, pattern: &str) -> Result<(crate::RawFrame,FilesReport), RavenError> {
+            let paths = expand_glob(pattern)?;
+
+            if paths.is_empty() {
+                return Err(RavenError::NoFilesFound { pattern: String::from(pattern) })
+            }
+
+            self.read_paths(paths)
+        }
+
+        /// Creates a RawFrame concatenating all the files with `csv` extension of a directory, loaded in alphabetical order of their names.
+        /// The files that can not be loaded or concatenated are listed in the FilesReport.
+        ///
+        /// # Arguments
+        ///
+        /// * `dir_path` - An OsString that holds the path of the directory
+        pub fn read_dir(&self, dir_path: OsString) -> Result<(crate::RawFrame,FilesReport), RavenError> {
+            let mut paths = Vec::new();
+            for entry in std::fs::read_dir(&dir_path)? {
+                let path = entry?.path();
+                if path.is_file() && path.extension().map_or(false, |ext| ext.eq_ignore_ascii_case("csv")) {
+                    paths.push(path);
+                }
+            }
+
+            if paths.is_empty() {
+                return Err(RavenError::NoFilesFound { pattern: Path::new(&dir_path).join("*.csv").display().to_string() })
+            }
+
+            paths.sort();
+            self.read_paths(paths)
+        }
+
+        /// Loads the files, in parallel if possible, and concatenates them in the given order.
+        fn read_paths(&self, paths: Vec<PathBuf>) -> Result<(crate::RawFrame,FilesReport), RavenError> {
+            let hilos = match self.threads {
+                0 => std::thread::available_parallelism().map_or(1, |n| n.get()),
+                n => n,
+            }.min(paths.len()).max(1);
+
+            let mut cargados: Vec<Option<Result<crate::RawFrame, RavenError>>> = (0..paths.len()).map(|_| None).collect();
+
+            if hilos == 1 {
+                for (path, cargado) in paths.iter().zip(cargados.iter_mut()) {
+                    *cargado = Some(self.read_file(path));
+                }
+            } else {
+                let tamano = (paths.len() + hilos - 1) / hilos;
+                std::thread::scope(|scope| {
+                    for (rutas, salidas) in paths.chunks(tamano).zip(cargados.chunks_mut(tamano)) {
+                        scope.spawn(move || {
+                            for (path, cargado) in rutas.iter().zip(salidas.iter_mut()) {
+                                *cargado = Some(self.read_file(path));
+                            }
+                        });
+                    }
+                });
+            }
+
+            let mut report = FilesReport::default();
+            let mut base: Option<crate::RawFrame> = None;
+
+            for (path, cargado) in paths.into_iter().zip(cargados) {
+                let resultado = match (cargado, base.as_mut()) {
+                    (Some(Ok(datos)), None) => {
+                        base = Some(datos);
+                        Ok(())
+                    },
+                    (Some(Ok(datos)), Some(base)) => base.concat_by_name(datos, self.concat_mode),
+                    (Some(Err(error)), _) => Err(error),
+                    (None, _) => Ok(()),
+                };
+
+                match resultado {
+                    Ok(()) => report.loaded.push(path),
+                    Err(error) => report.failed.push(FileFailure { path, error }),
+                }
+            }
+
+            let datos = base.unwrap_or(crate::RawFrame { columns: csv::StringRecord::new(), records: Vec::new() });
+
+            Ok((datos, report))
+        }
+
+        /// Loads one file and adds the source column if it was requested.
+        fn read_file(&self, path: &Path) -> Result<crate::RawFrame, RavenError> {
+            let mut datos = crate::RawFrame::from_os_string_with(path.as_os_str().to_os_string(), self)?;
+
+            if let Some(source) = &self.source_column {
+                if datos.columns.iter().any(|nombre| nombre == source) {
+                    return Err(RavenError::DuplicateColumn { name: source.clone() })
+                }
+
+                let ancho = datos.columns.len();
+                let ruta = path.display().to_string();
+                datos.columns.push_field(source);
+                for record in datos.records.iter_mut() {
+                    let mut nuevo: csv::StringRecord = record.iter().take(ancho).collect();
+                    while nuevo.len() < ancho {
+                        nuevo.push_field("");
+                    }
+                    nuevo.push_field(&ruta);
+                    for campo in record.iter().skip(ancho) {
+                        nuevo.push_field(campo);
+                    }
+                    *record = nuevo;
+                }
+            }
+
+            Ok(datos)
+        }
+
+        /// Creates a RawFrame from an os_string using these options.
+        ///
+        /// # Arguments
+        ///
+        /// * `file_path` - An OsString that holds the path of CSV file
+        pub fn read_os_string(&self, file_path: OsString) -> Result<crate::RawFrame, RavenError> {
+            crate::RawFrame::from_os_string_with(file_path, self)
+        }
+
+        /// Creates a RawFrame from an os_string using these options and returns it with the LoadReport of the malformed rows.
+        ///
+        /// # Arguments
+        ///
+        /// * `file_path` - An OsString that holds the path of CSV file
+        pub fn read_os_string_report(&self, file_path: OsString) -> Result<(crate::RawFrame,LoadReport), RavenError> {
+            let ((columns,records),report) = get_data_report(file_path, self)?;
+
+            Ok((crate::RawFrame{columns, records},report))
+        }
+
+        /// Creates a RawFrame from any reader using these options and returns it with the LoadReport of the malformed rows.
+        ///
+        /// # Arguments
+        ///
+        /// * `reader` - A type implementing std::io::Read with the CSV data
+        pub fn read_reader_report<R: std::io::Read>(&self, reader: R) -> Result<(crate::RawFrame,LoadReport), RavenError> {
+            let ((columns,records),report) = get_data_rdr_report(reader, self)?;
+
+            Ok((crate::RawFrame{columns, records},report))
+        }
+
+        /// Creates a RawFrame from any reader using these options.
+        ///
+        /// # Arguments
+        ///
+        /// * `reader` - A type implementing std::io::Read with the CSV data
+        pub fn read_reader<R: std::io::Read>(&self, reader: R) -> Result<crate::RawFrame, RavenError> {
+            crate::RawFrame::from_reader_with(reader, self)
+        }
+
+        /// Creates a RawFrame from a string slice holding CSV data using these options.
+        ///
+        /// # Arguments
+        ///
+        /// * `data` - A string slice with the CSV data
+        pub fn read_str(&self, data: &str) -> Result<crate::RawFrame, RavenError> {
+            crate::RawFrame::from_reader_with(data.as_bytes(), self)
+        }
+
+        /// Creates a RawFrame from the standard input using these options.
+        pub fn read_stdin(&self) -> Result<crate::RawFrame, RavenError> {
+            crate::RawFrame::from_reader_with(std::io::stdin().lock(), self)
+        }
+
+        /// Returns a csv::ReaderBuilder configured with these options.
+        pub(crate) fn csv_builder(&self) -> csv::ReaderBuilder {
+            let mut builder = csv::ReaderBuilder::new();
+            builder
+                .delimiter(self.delimiter)
+                .quote(self.quote)
+                .escape(self.escape)
+                .double_quote(self.escape.is_none())
+                .comment(self.comment)
+                .has_headers(self.has_headers)
+                .flexible(self.flexible)
+                .trim(self.trim);
+            builder
+        }
+
+        fn trims_headers(&self) -> bool {
+            self.trim == csv::Trim::All || self.trim == csv::Trim::Headers
+        }
+
+        fn trims_fields(&self) -> bool {
+            self.trim == csv::Trim::All || self.trim == csv::Trim::Fields
+        }
+
+        /// Returns the null tokens of each column position, or an error if a column with its own tokens does not exist.
+        fn null_tokens<'a>(&'a self, columns: &csv::StringRecord) -> Result<Vec<&'a [String]>, RavenError> {
+            let mut tokens: Vec<&[String]> = vec![&self.null_values; columns.len()];
+
+            for (nombre, propios) in &self.column_null_values {
+                match columns.iter().position(|col| col == nombre) {
+                    Some(n) => tokens[n] = propios,
+                    None => return Err(RavenError::ColumnNotFound {
+                        name: nombre.clone(),
+                        available: columns.iter().map(String::from).collect(),
+                    }),
+                }
+            }
+
+            Ok(tokens)
+        }
+    }
+
+    /// Replaces the fields equal to a null token of their column with empty fields.
+    fn blank_nulls(records: &mut [csv::StringRecord], tokens: &[&[String]]) {
+        let es_nulo = |n: usize, cadena: &str| tokens.get(n).map_or(false, |propios| propios.iter().any(|token| token == cadena));
+
+        for record in records.iter_mut() {
+            if !record.iter().enumerate().any(|(n, cadena)| !cadena.is_empty() && es_nulo(n, cadena)) {
+                continue;
+            }
+
+            let mut nuevo: csv::StringRecord = record.iter().enumerate()
+                .map(|(n, cadena)| if es_nulo(n, cadena) { "" } else { cadena })
+                .collect();
+            nuevo.set_position(record.position().cloned());
+            *record = nuevo;
+        }
+    }
 
     /// Returns an OsString for terminal argument in position n or an error if it is not possible to read it
-    pub fn read_arg(n: usize) -> Result<OsString, Box<dyn Error>> {
+    pub fn read_arg(n: usize) -> Result<OsString, RavenError> {
         match env::args_os().nth(n) {
             Some(file_path) => Ok(file_path),
-            None => Err(From::from("No se pudo leer el argumento"))
+            None => Err(RavenError::MissingArgument(n))
         }
     }
 
     /// Returns a tuple with column names and a Vec of rows in a csv file. Each row is represented as a csv::StringRecord
-    pub fn get_data_src(file_path: OsString) -> Result<(csv::StringRecord,Vec<csv::StringRecord>), Box<dyn Error>> {
+    pub fn get_data_src(file_path: OsString) -> Result<Table<csv::StringRecord>, RavenError> {
 
         let mut vector: Vec<csv::StringRecord> = Vec::new();
 
-        let mut rdr = csv::ReaderBuilder::new()
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .from_path(file_path)?;
+        let mut rdr = ReadOptions::default().csv_builder().from_path(file_path)?;
 
         let columns = rdr.headers()?.clone();
 
@@ -1087,14 +3761,11 @@ pub mod reading {
     }
 
     /// Returns a tuple with column names and a Vec of rows in a csv file. Each row is represented as a Vec<String>
-    pub fn get_data_vec(file_path: OsString) -> Result<(csv::StringRecord,Vec<Vec<String>>), Box<dyn Error>> {
+    pub fn get_data_vec(file_path: OsString) -> Result<Table<Vec<String>>, RavenError> {
 
         let mut vector: Vec<Vec<String>> = Vec::new();
 
-        let mut rdr = csv::ReaderBuilder::new()
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .from_path(file_path)?;
+        let mut rdr = ReadOptions::default().csv_builder().from_path(file_path)?;
 
         let columns = rdr.headers()?.clone();
 
@@ -1107,14 +3778,11 @@ pub mod reading {
     }
 
     /// Returns a tuple with column names and a Vec of rows in a csv file. Each row are represented as a HashMap
-    pub fn get_data_hsm(file_path: OsString) -> Result<(csv::StringRecord,Vec<HashMap<String, String>>), Box<dyn Error>> {
+    pub fn get_data_hsm(file_path: OsString) -> Result<Table<HashMap<String, String>>, RavenError> {
 
         let mut vector: Vec<HashMap<String, String>> = Vec::new();
 
-        let mut rdr = csv::ReaderBuilder::new()
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .from_path(file_path)?;
+        let mut rdr = ReadOptions::default().csv_builder().from_path(file_path)?;
 
         let columns = rdr.headers()?.clone();
 
@@ -1127,14 +3795,11 @@ pub mod reading {
     }
 
     /// Returns a tuple with column names and a Vec of rows in a csv file. Each row are represented as a csv::ByteRecord
-    pub fn get_data_brc(file_path: OsString) -> Result<(csv::StringRecord,Vec<csv::ByteRecord>), Box<dyn Error>> {
+    pub fn get_data_brc(file_path: OsString) -> Result<Table<csv::ByteRecord>, RavenError> {
 
         let mut vector: Vec<csv::ByteRecord> = Vec::new();
 
-        let mut rdr = csv::ReaderBuilder::new()
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .from_path(file_path)?;
+        let mut rdr = ReadOptions::default().csv_builder().from_path(file_path)?;
 
         let columns = rdr.headers()?.clone();
 
@@ -1147,67 +3812,169 @@ pub mod reading {
     }
 
     /// Returns a tuple with column names and a Vec of rows in a csv file. Each row are represented as a csv::StringRecord
-    pub fn get_data_src_h(file_path: OsString) -> Result<(csv::StringRecord,Vec<csv::StringRecord>), Box<dyn Error>> {
+    pub fn get_data_src_h(file_path: OsString) -> Result<Table<csv::StringRecord>, RavenError> {
 
-        let mut vector: Vec<csv::StringRecord> = Vec::new();
+        get_data_opt(file_path, &ReadOptions::default())
+    }
 
-        let mut rdr = csv::ReaderBuilder::new()
-            .flexible(true)
-            .trim(csv::Trim::All)
-            .from_path(file_path)?;
+    /// Returns a tuple with column names and a Vec of rows in a csv file. Each row are represented as a csv::StringRecord
+    pub fn get_data_brc_h(file_path: OsString) -> Result<Table<csv::StringRecord>, RavenError> {
 
-        let columns = rdr.byte_headers()?.clone();
-        let columns = csv::StringRecord::from_byte_record_lossy(columns);
+        get_data_opt(file_path, ReadOptions::new().lossy(true))
+    }
 
-        let mut iter = rdr.into_records();
+    /// Returns a tuple with column names and a Vec of rows in a csv file parsed with the given options. Each row are represented as a csv::StringRecord
+    pub fn get_data_opt(file_path: OsString, options: &ReadOptions) -> Result<Table<csv::StringRecord>, RavenError> {
 
-        loop {
-            let row = match iter.next() {
-                Some(rec) => rec,
-                None => break,
-            };
+        let (table,_) = get_data_report(file_path, options)?;
 
-            let record = match row {
-                Ok(rec) => rec,
-                Err(_) => continue,
-            };
+        Ok(table)
+    }
 
-            vector.push(record);
-        }
+    /// Returns a tuple with column names and a Vec of rows read from any reader parsed with the given options. Each row are represented as a csv::StringRecord
+    pub fn get_data_rdr<R: std::io::Read>(reader: R, options: &ReadOptions) -> Result<Table<csv::StringRecord>, RavenError> {
 
-        Ok((columns,vector))
-    }
+        let (table,_) = get_data_rdr_report(reader, options)?;
 
-    /// Returns a tuple with column names and a Vec of rows in a csv file. Each row are represented as a csv::StringRecord
-    pub fn get_data_brc_h(file_path: OsString) -> Result<(csv::StringRecord,Vec<csv::StringRecord>), Box<dyn Error>> {
+        Ok(table)
+    }
 
-        let mut vector: Vec<csv::StringRecord> = Vec::new();
+    /// Returns the table of a csv file parsed with the given options and the LoadReport with the malformed rows.
+    pub fn get_data_report(file_path: OsString, options: &ReadOptions) -> Result<(Table<csv::StringRecord>,LoadReport), RavenError> {
 
-        let mut rdr = csv::ReaderBuilder::new()
+        let rdr = options.csv_builder()
+            .has_headers(false)
             .flexible(true)
-            .trim(csv::Trim::All)
+            .trim(csv::Trim::None)
             .from_path(file_path)?;
 
-            let columns = rdr.byte_headers()?.clone();
-            let columns = csv::StringRecord::from_byte_record_lossy(columns);
+        read_records(rdr, options)
+    }
+
+    /// Returns the table read from any reader parsed with the given options and the LoadReport with the malformed rows.
+    pub fn get_data_rdr_report<R: std::io::Read>(reader: R, options: &ReadOptions) -> Result<(Table<csv::StringRecord>,LoadReport), RavenError> {
+
+        let rdr = options.csv_builder()
+            .has_headers(false)
+            .flexible(true)
+            .trim(csv::Trim::None)
+            .from_reader(reader);
+
+        read_records(rdr, options)
+    }
+
+    /// Reads the rows of a flexible csv::Reader built without headers applying the skip, header, trim, flexible, max rows and bad rows options.
+    fn read_records<R: std::io::Read>(rdr: csv::Reader<R>, options: &ReadOptions) -> Result<(Table<csv::StringRecord>,LoadReport), RavenError> {
 
-        let mut iter = rdr.into_byte_records();
+        let mut vector: Vec<csv::StringRecord> = Vec::new();
+        let mut columns: Option<csv::StringRecord> = None;
+        let mut report = LoadReport::default();
+        let mut cuarentena: Vec<csv::StringRecord> = Vec::new();
+
+        let mut iter = rdr.into_byte_records().skip(options.skip_rows);
+
+        if options.has_headers {
+            if let Some(row) = iter.next() {
+                let mut header = csv::StringRecord::from_byte_record_lossy(row?);
+                if options.trims_headers() {
+                    header.trim();
+                }
+                columns = Some(header);
+            }
+        }
+
+        for row in iter {
+
+            if options.max_rows.map_or(false, |max| vector.len() >= max) {
+                break;
+            }
+
+            let row = match row {
+                Ok(row) => row,
+                Err(err) => {
+                    let bad_row = BadRow::from_error(&err);
+                    if options.bad_rows == BadRowPolicy::Fail {
+                        return Err(RavenError::MalformedRow { line: bad_row.line, byte: bad_row.byte, error: bad_row.error });
+                    }
+
+                    report.bad_rows.push(bad_row);
+                    // The reader can not go past an I/O error, the rows read until it are kept.
+                    if let csv::ErrorKind::Io(_) = err.kind() {
+                        break;
+                    }
+                    continue;
+                }
+            };
+
+            let mut problema: Option<String> = None;
+
+            if !options.flexible {
+                let ancho = match &columns {
+                    Some(header) => Some(header.len()),
+                    None => vector.first().map(|first| first.len()),
+                };
+                if let Some(ancho) = ancho.filter(|ancho| *ancho != row.len()) {
+                    problema = Some(match columns {
+                        Some(_) => format!("found record with {} fields, but the header has {} fields", row.len(), ancho),
+                        None => format!("found record with {} fields, but the first record has {} fields", row.len(), ancho),
+                    });
+                }
+            }
 
-        loop {
-            let row = match iter.next() {
-                Some(rec) => rec,
-                None => break,
+            let decoded = match problema {
+                Some(mensaje) => Err(mensaje),
+                None if options.lossy => Ok(csv::StringRecord::from_byte_record_lossy(row.clone())),
+                None => csv::StringRecord::from_byte_record(row.clone()).map_err(|err| err.to_string()),
             };
 
-            let record = match row {
-                Ok(rec) => csv::StringRecord::from_byte_record_lossy(rec),
-                Err(_) => continue,
+            let mut record = match decoded {
+                Ok(rec) => rec,
+                Err(mensaje) => {
+                    let bad_row = BadRow::new(&row, mensaje);
+
+                    match options.bad_rows {
+                        BadRowPolicy::Skip => {},
+                        BadRowPolicy::Fail => {
+                            return Err(RavenError::MalformedRow { line: bad_row.line, byte: bad_row.byte, error: bad_row.error });
+                        },
+                        BadRowPolicy::Quarantine => {
+                            let mut raw = csv::StringRecord::from_byte_record_lossy(row);
+                            if options.trims_fields() {
+                                raw.trim();
+                            }
+                            cuarentena.push(raw);
+                        },
+                    }
+
+                    report.bad_rows.push(bad_row);
+                    continue;
+                }
             };
 
+            if options.trims_fields() {
+                record.trim();
+            }
+
             vector.push(record);
         }
 
-        Ok((columns,vector))
+        let columns = match columns {
+            Some(header) => header,
+            None => {
+                let ancho = vector.iter().chain(cuarentena.iter()).map(|record| record.len()).max().unwrap_or(0);
+                (0..ancho).map(|n| format!("col_{}", n)).collect()
+            }
+        };
+
+        if !options.null_values.is_empty() || !options.column_null_values.is_empty() {
+            blank_nulls(&mut vector, &options.null_tokens(&columns)?);
+        }
+
+        if options.bad_rows == BadRowPolicy::Quarantine {
+            report.quarantine = Some(crate::RawFrame{columns: columns.clone(), records: cuarentena});
+        }
+
+        Ok(((columns,vector),report))
     }
 
-}
\ No newline at end of file
+}
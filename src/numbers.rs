@@ -0,0 +1,190 @@
+//! Locale-aware number parsing.
+//!
+//! Many data sources write numbers with thousand separators (`1,234.50`), decimal commas (`1.234,50`), percentages (`12%`)
+//! or currency symbols (`$3.20`). Those strings can not be parsed with `FromStr`. A `NumberFormat` describes how the numbers
+//! are written and normalizes each string to the plain format before parsing it, in this way it can be used with any
+//! type implementing `FromStr`.
+//!
+//! Thousand separators are only accepted in valid positions, `1,234` is a number but `1,2` is not.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::numbers::NumberFormat;
+//!
+//! let formato = NumberFormat::european().percent(true).currency(&["€"]);
+//!
+//! assert_eq!(formato.parse::<f64>("1.234,50"), Some(1234.5));
+//! assert_eq!(formato.parse::<f64>("-3,20 €"), Some(-3.2));
+//! assert_eq!(formato.parse::<i32>("12%"), Some(12));
+//! assert_eq!(formato.parse::<f64>("1,2,3"), None);
+//! ```
+
+use std::borrow::Cow;
+
+/// Description of how numbers are written in a data source.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NumberFormat {
+    decimal: char,
+    grouping: Option<char>,
+    percent: bool,
+    currency: Vec<String>,
+}
+
+impl Default for NumberFormat {
+    fn default() -> NumberFormat {
+        NumberFormat { decimal: '.', grouping: None, percent: false, currency: Vec::new() }
+    }
+}
+
+impl NumberFormat {
+    /// Creates the plain format used by `FromStr`: decimal point and no thousand separators, percentages or currency symbols.
+    pub fn new() -> NumberFormat {
+        NumberFormat::default()
+    }
+
+    /// Creates a format with decimal point and comma as thousand separator, like `1,234.50`.
+    pub fn english() -> NumberFormat {
+        NumberFormat::new().decimal('.').grouping(Some(','))
+    }
+
+    /// Creates a format with decimal comma and point as thousand separator, like `1.234,50`.
+    pub fn european() -> NumberFormat {
+        NumberFormat::new().decimal(',').grouping(Some('.'))
+    }
+
+    /// Sets the decimal separator.
+    pub fn decimal(mut self, decimal: char) -> NumberFormat {
+        self.decimal = decimal;
+        self
+    }
+
+    /// Sets the thousand separator. None means that numbers have no thousand separators.
+    pub fn grouping(mut self, grouping: Option<char>) -> NumberFormat {
+        self.grouping = grouping;
+        self
+    }
+
+    /// Sets if a trailing `%` is removed. The number is not divided by 100, `12%` is parsed as 12.
+    pub fn percent(mut self, percent: bool) -> NumberFormat {
+        self.percent = percent;
+        self
+    }
+
+    /// Sets the currency symbols or codes removed at the start or the end of the numbers, for example `&["$", "MXN"]`. A number can have
+    /// one symbol and one sign, written before or after the symbol.
+    pub fn currency(mut self, symbols: &[&str]) -> NumberFormat {
+        self.currency = symbols.iter().map(|symbol| String::from(*symbol)).collect();
+        self
+    }
+
+    /// Returns true if the format is the plain format used by `FromStr`.
+    pub fn is_plain(&self) -> bool {
+        self.decimal == '.' && self.grouping.is_none() && !self.percent && self.currency.is_empty()
+    }
+
+    /// Returns the string written in the plain format, or None if it is not a valid number in this format.
+    /// Strings which do not look like numbers, like `inf` or `NaN`, are returned without changes.
+    ///
+    /// # Arguments
+    ///
+    /// * `cadena` - A string slice with the number
+    pub fn normalize<'a>(&self, cadena: &'a str) -> Option<Cow<'a, str>> {
+        if self.is_plain() {
+            return Some(Cow::Borrowed(cadena))
+        }
+
+        let mut resto = cadena.trim();
+        let mut negativo = false;
+
+        if self.percent {
+            if let Some(sin) = resto.strip_suffix('%') {
+                resto = sin.trim_end();
+            }
+        }
+
+        // At most one sign and one currency symbol, the sign can be written before or after the symbol.
+        let mut signo = false;
+        let mut moneda = false;
+        for _ in 0..2 {
+            if let Some(sin) = resto.strip_prefix('-') {
+                if signo {
+                    return None
+                }
+                signo = true;
+                negativo = true;
+                resto = sin.trim_start();
+            } else if let Some(sin) = resto.strip_prefix('+') {
+                if signo {
+                    return None
+                }
+                signo = true;
+                resto = sin.trim_start();
+            }
+            for symbol in &self.currency {
+                if moneda {
+                    break
+                }
+                if let Some(sin) = resto.strip_prefix(symbol.as_str()) {
+                    moneda = true;
+                    resto = sin.trim_start();
+                } else if let Some(sin) = resto.strip_suffix(symbol.as_str()) {
+                    moneda = true;
+                    resto = sin.trim_end();
+                }
+            }
+        }
+
+        if resto.starts_with(['-', '+']) {
+            return None
+        }
+
+        if !resto.starts_with(|c: char| c.is_ascii_digit() || c == self.decimal) {
+            return Some(Cow::Borrowed(cadena))
+        }
+
+        let (entera, fraccion) = match resto.find(self.decimal) {
+            Some(n) => (&resto[..n], Some(&resto[n + self.decimal.len_utf8()..])),
+            None => (resto, None),
+        };
+
+        let entera = match self.grouping {
+            Some(grouping) if entera.contains(grouping) => {
+                let grupos: Vec<&str> = entera.split(grouping).collect();
+                let validos = grupos.iter().enumerate().all(|(n, grupo)| {
+                    let ancho = if n == 0 { (1..=3).contains(&grupo.len()) } else { grupo.len() == 3 };
+                    ancho && grupo.bytes().all(|b| b.is_ascii_digit())
+                });
+                if !validos {
+                    return None
+                }
+                grupos.concat()
+            },
+            _ => String::from(entera),
+        };
+
+        let mut salida = String::with_capacity(resto.len() + 1);
+        if negativo {
+            salida.push('-');
+        }
+        salida.push_str(&entera);
+        if let Some(fraccion) = fraccion {
+            if fraccion.contains(self.decimal) || self.grouping.map_or(false, |grouping| fraccion.contains(grouping)) {
+                return None
+            }
+            salida.push('.');
+            salida.push_str(fraccion);
+        }
+
+        Some(Cow::Owned(salida))
+    }
+
+    /// Parses a string written in this format to a generic type, or returns None if it is not possible.
+    ///
+    /// # Arguments
+    ///
+    /// * `cadena` - A string slice with the number
+    pub fn parse<T: std::str::FromStr>(&self, cadena: &str) -> Option<T> {
+        self.normalize(cadena)?.parse::<T>().ok()
+    }
+}
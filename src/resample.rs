@@ -0,0 +1,146 @@
+//! Time-series resampling.
+//!
+//! Resampling groups the rows of a RawFrame in calendar periods (days, weeks, months or quarters) using a date column and
+//! aggregates the value columns of each period. The result is a new RawFrame with one row per period, sorted by date, where
+//! the date column holds the first day of each period. Periods without rows can be left out or filled with null or imputed values.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::aggregate::Aggregation;
+//! use ravencol::resample::{Frequency, GapFill};
+//!
+//! let datos: RawFrame = "fecha,ventas\n2020-01-15,10\n2020-01-20,5\n2020-03-02,7\n".parse().unwrap();
+//!
+//! let mensual = datos.resample("fecha", None, Frequency::Month, &[("ventas", Aggregation::Sum)], GapFill::Value(0.0)).unwrap();
+//!
+//! assert_eq!(mensual.columns.iter().collect::<Vec<&str>>(), vec!["fecha", "ventas_sum"]);
+//! assert_eq!(mensual.records[0].iter().collect::<Vec<&str>>(), vec!["2020-01-01", "15"]);
+//! assert_eq!(mensual.records[1].iter().collect::<Vec<&str>>(), vec!["2020-02-01", "0"]);
+//! assert_eq!(mensual.records[2].iter().collect::<Vec<&str>>(), vec!["2020-03-01", "7"]);
+//! ```
+
+use crate::RavenError;
+use crate::aggregate::{Accumulator, Aggregation};
+use crate::dates::{Date, DateTime};
+use std::collections::BTreeMap;
+
+/// Length of the periods used to group the rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Frequency {
+    Day,
+    /// Weeks start on Monday.
+    Week,
+    Month,
+    /// Quarters start in January, April, July and October.
+    Quarter,
+}
+
+impl Frequency {
+    /// Returns the first day of the period which contains the date.
+    pub fn start(&self, date: Date) -> Date {
+        match self {
+            Frequency::Day => date,
+            Frequency::Week => Date::from_days_since_epoch(date.days_since_epoch() - i64::from(date.weekday())),
+            Frequency::Month => Date::new(date.year(), date.month(), 1).unwrap_or(date),
+            Frequency::Quarter => Date::new(date.year(), (date.month() - 1) / 3 * 3 + 1, 1).unwrap_or(date),
+        }
+    }
+
+    /// Returns the first day of the period after the one which contains the date.
+    pub fn next(&self, date: Date) -> Date {
+        let meses = match self {
+            Frequency::Day => return Date::from_days_since_epoch(date.days_since_epoch() + 1),
+            Frequency::Week => return Date::from_days_since_epoch(self.start(date).days_since_epoch() + 7),
+            Frequency::Month => 1,
+            Frequency::Quarter => 3,
+        };
+
+        let inicio = self.start(date);
+        let total = inicio.year() * 12 + inicio.month() as i32 - 1 + meses;
+        Date::new(total.div_euclid(12), total.rem_euclid(12) as u32 + 1, 1).unwrap_or(inicio)
+    }
+}
+
+/// What to do with the periods between the first and the last date which have no rows.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum GapFill {
+    /// The periods without rows are left out of the result.
+    Omit,
+    /// The periods without rows are included with empty fields, except the count columns which have 0.
+    Null,
+    /// The periods without rows are included with the value in the aggregated columns, except the count columns which have 0.
+    Value(f64),
+}
+
+/// Groups the rows of a RawFrame by period and aggregates the value columns.
+pub(crate) fn resample(raw: &crate::RawFrame, date_column: &str, format: Option<&str>, frequency: Frequency, aggs: &[(&str, Aggregation)], fill: GapFill) -> Result<crate::RawFrame, RavenError> {
+    let nombres: Vec<&str> = aggs.iter().map(|(nombre, _)| *nombre).collect();
+    let positions = raw.col_positions(&nombres)?;
+    let fechas: Vec<Option<DateTime>> = raw.col_date::<DateTime>(date_column, format)?.collect();
+
+    let mut grupos: BTreeMap<Date, Vec<Accumulator>> = BTreeMap::new();
+
+    for (record, fecha) in raw.records.iter().zip(fechas) {
+        let fecha = match fecha {
+            Some(fecha) => fecha.date(),
+            None => continue,
+        };
+
+        let acumuladores = grupos.entry(frequency.start(fecha)).or_insert_with(|| aggs.iter().map(|(_, aggregation)| Accumulator::new(*aggregation)).collect());
+
+        for (acumulador, position) in acumuladores.iter_mut().zip(positions.iter()) {
+            if let Some(cadena) = record.get(*position).filter(|cadena| !cadena.is_empty()) {
+                acumulador.push(cadena);
+            }
+        }
+    }
+
+    let mut columns = csv::StringRecord::new();
+    columns.push_field(date_column);
+    for (nombre, aggregation) in aggs {
+        columns.push_field(&format!("{}_{}", nombre, aggregation));
+    }
+
+    let mut records = Vec::new();
+    let mut esperada: Option<Date> = None;
+
+    for (inicio, acumuladores) in &grupos {
+        if let Some(esperada) = esperada {
+            let relleno = match fill {
+                GapFill::Omit => None,
+                GapFill::Null => Some(String::new()),
+                GapFill::Value(valor) => Some(valor.to_string()),
+            };
+
+            if let Some(relleno) = relleno {
+                let mut hueco = esperada;
+                while hueco < *inicio {
+                    let mut record = csv::StringRecord::new();
+                    record.push_field(&hueco.to_string());
+                    for (_, aggregation) in aggs {
+                        match aggregation {
+                            Aggregation::Count | Aggregation::NUnique => record.push_field("0"),
+                            _ => record.push_field(&relleno),
+                        }
+                    }
+                    records.push(record);
+                    hueco = frequency.next(hueco);
+                }
+            }
+        }
+
+        let mut record = csv::StringRecord::new();
+        record.push_field(&inicio.to_string());
+        for acumulador in acumuladores {
+            record.push_field(&acumulador.result().unwrap_or_default());
+        }
+        records.push(record);
+
+        esperada = Some(frequency.next(*inicio));
+    }
+
+    Ok(crate::RawFrame { columns, records })
+}
+
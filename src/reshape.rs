@@ -0,0 +1,117 @@
+//! Reshaping between wide and long formats.
+//!
+//! `melt` turns a wide RawFrame, with one column per variable (for example one column per year), into a long RawFrame with
+//! a column for the name of the variable and a column for its value. `pivot` does the opposite: the distinct values of a
+//! column become new columns, in the order in which they first appear, and the cells are filled with the aggregation of the
+//! values of the records with the same index and column. Cells without records are empty fields.
+//!
+//! `Aggregation::First` and `Aggregation::Last` keep the first or last non empty field as written, so they can pivot text
+//! values and numbers like `007` are not rewritten. The other aggregations only use the values which can be parsed as f64
+//! and write their result as a number.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::aggregate::Aggregation;
+//!
+//! let ancho: RawFrame = "estado,2019,2020\nColima,5,7\nJalisco,3,4\n".parse().unwrap();
+//!
+//! let largo = ancho.melt(&["estado"], &[], "anio", "valor").unwrap();
+//! assert_eq!(largo.columns.iter().collect::<Vec<&str>>(), vec!["estado", "anio", "valor"]);
+//! assert_eq!(largo.records[1].iter().collect::<Vec<&str>>(), vec!["Jalisco", "2019", "3"]);
+//!
+//! let otra_vez = largo.pivot(&["estado"], "anio", "valor", Aggregation::Sum).unwrap();
+//! assert_eq!(otra_vez.columns, ancho.columns);
+//! assert_eq!(otra_vez.records, ancho.records);
+//! ```
+
+use crate::RavenError;
+use crate::aggregate::{Accumulator, Aggregation};
+use std::collections::HashMap;
+
+/// Turns the value columns of a RawFrame into records with the name of the column and its value.
+pub(crate) fn melt(raw: &crate::RawFrame, id_cols: &[&str], value_cols: &[&str], var_name: &str, value_name: &str) -> Result<crate::RawFrame, RavenError> {
+    let ids = raw.col_positions(id_cols)?;
+    let valores = if value_cols.is_empty() {
+        (0..raw.columns.len()).filter(|n| !ids.contains(n)).collect()
+    } else {
+        raw.col_positions(value_cols)?
+    };
+
+    let mut columns = csv::StringRecord::new();
+    for position in &ids {
+        columns.push_field(&raw.columns[*position]);
+    }
+    columns.push_field(var_name);
+    columns.push_field(value_name);
+    crate::check_unique(columns.iter())?;
+
+    let mut records = Vec::with_capacity(valores.len() * raw.records.len());
+    for valor in &valores {
+        for record in &raw.records {
+            let mut nuevo = csv::StringRecord::new();
+            for position in &ids {
+                nuevo.push_field(record.get(*position).unwrap_or(""));
+            }
+            nuevo.push_field(&raw.columns[*valor]);
+            nuevo.push_field(record.get(*valor).unwrap_or(""));
+            records.push(nuevo);
+        }
+    }
+
+    Ok(crate::RawFrame { columns, records })
+}
+
+/// Turns the distinct values of a column into new columns filled with the aggregation of a value column.
+pub(crate) fn pivot(raw: &crate::RawFrame, index: &[&str], columns: &str, values: &str, agg: Aggregation) -> Result<crate::RawFrame, RavenError> {
+    let indices = raw.col_positions(index)?;
+    let columna = raw.col_position(columns)?;
+    let valor = raw.col_position(values)?;
+    crate::check_unique(index.iter().copied())?;
+
+    let mut filas: HashMap<Vec<&str>, usize> = HashMap::new();
+    let mut llaves: Vec<Vec<&str>> = Vec::new();
+    let mut nombres: HashMap<&str, usize> = HashMap::new();
+    let mut encabezados: Vec<&str> = Vec::new();
+    let mut celdas: HashMap<(usize, usize), Accumulator> = HashMap::new();
+
+    for record in &raw.records {
+        let llave: Vec<&str> = indices.iter().map(|position| record.get(*position).unwrap_or("")).collect();
+        let fila = *filas.entry(llave.clone()).or_insert_with(|| {
+            llaves.push(llave);
+            llaves.len() - 1
+        });
+
+        let nombre = record.get(columna).unwrap_or("");
+        let col = *nombres.entry(nombre).or_insert_with(|| {
+            encabezados.push(nombre);
+            encabezados.len() - 1
+        });
+
+        let acumulador = celdas.entry((fila, col)).or_insert_with(|| Accumulator::new(agg));
+        if let Some(cadena) = record.get(valor).filter(|cadena| !cadena.is_empty()) {
+            acumulador.push(cadena);
+        }
+    }
+
+    let mut salida_columns = csv::StringRecord::new();
+    for nombre in index.iter().chain(encabezados.iter()) {
+        salida_columns.push_field(nombre);
+    }
+    crate::check_unique(salida_columns.iter())?;
+
+    let records = llaves.iter().enumerate().map(|(fila, llave)| {
+        let mut record = csv::StringRecord::new();
+        for campo in llave {
+            record.push_field(campo);
+        }
+        for col in 0..encabezados.len() {
+            let celda = celdas.get(&(fila, col)).and_then(Accumulator::result);
+            record.push_field(&celda.unwrap_or_default());
+        }
+        record
+    }).collect();
+
+    Ok(crate::RawFrame { columns: salida_columns, records })
+}
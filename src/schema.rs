@@ -0,0 +1,149 @@
+//! Schema inference over whole columns.
+//!
+//! `RawFrame::column` guesses the type of each cell independently. The functions of this module look at all the cells of
+//! a column (or a sample of rows) and decide one dominant type for the whole column, in this way it is possible to know
+//! what a file contains before writing typed code against it.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::dataframe::DataType;
+//!
+//! let datos: RawFrame = "id,precio,fecha\n1,2.5,2020-01-01\n2,3,2020-01-02\n3,,2020-01-03\n".parse().unwrap();
+//!
+//! let schema = datos.infer_schema(None);
+//! assert_eq!(schema.get("id").unwrap().data_type, DataType::Int);
+//! assert_eq!(schema.get("precio").unwrap().data_type, DataType::Float);
+//! assert!(schema.get("precio").unwrap().nullable);
+//! assert_eq!(schema.get("fecha").unwrap().data_type, DataType::Date);
+//!
+//! let frame = datos.to_dataframe(&schema.types()).unwrap();
+//! ```
+
+use crate::dataframe::DataType;
+use std::fmt;
+
+/// How to decide the type of a column with integers and floats.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum InferMode {
+    /// The type with more values wins, the values of the other type are counted as non-conforming when they can not be represented.
+    /// On ties the most general type wins.
+    Strict,
+    /// If a column has integers and floats it is a float column.
+    PromoteInts,
+}
+
+/// The inferred description of a column.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ColumnSchema {
+    /// Name of the column.
+    pub name: String,
+    /// Dominant type of the non null values of the column.
+    pub data_type: DataType,
+    /// True if the column has null values.
+    pub nullable: bool,
+    /// Number of null values in the inspected rows.
+    pub null_count: usize,
+    /// Ratio of the non null values which can not be represented with the dominant type.
+    pub invalid_ratio: f64,
+}
+
+/// The inferred description of all the columns of a RawFrame.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct Schema {
+    pub columns: Vec<ColumnSchema>,
+}
+
+impl Schema {
+    /// Returns the description of a column or None if column does not exists.
+    pub fn get(&self, column: &str) -> Option<&ColumnSchema> {
+        self.columns.iter().find(|col| col.name == column)
+    }
+
+    /// Returns the name and type of each column. The result can be used with `RawFrame::to_dataframe`.
+    pub fn types(&self) -> Vec<(&str, DataType)> {
+        self.columns.iter().map(|col| (col.name.as_str(), col.data_type)).collect()
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ancho = self.columns.iter().map(|col| col.name.chars().count()).max().unwrap_or(0).max(6);
+
+        writeln!(f, "{:<ancho$}  {:<6}  {:<8}  {:>7}", "column", "type", "nullable", "invalid", ancho = ancho)?;
+        for col in &self.columns {
+            writeln!(f, "{:<ancho$}  {:<6}  {:<8}  {:>6.2}%", col.name, col.data_type.to_string(), col.nullable, col.invalid_ratio * 100.0, ancho = ancho)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns the most specific type of a non null cell.
+pub(crate) fn classify(cadena: &str) -> DataType {
+    if cadena.parse::<i64>().is_ok() {
+        DataType::Int
+    } else if cadena.parse::<f64>().is_ok() {
+        DataType::Float
+    } else if cadena.parse::<bool>().is_ok() {
+        DataType::Bool
+    } else if cadena.parse::<crate::dates::Date>().is_ok() {
+        DataType::Date
+    } else {
+        DataType::Str
+    }
+}
+
+const ORDEN: [DataType; 5] = [DataType::Int, DataType::Float, DataType::Bool, DataType::Date, DataType::Str];
+
+fn slot(data_type: DataType) -> usize {
+    ORDEN.iter().position(|tipo| *tipo == data_type).unwrap_or(ORDEN.len() - 1)
+}
+
+/// Returns true if a value classified as `found` can be represented with the type `target`.
+pub(crate) fn conforms(found: DataType, target: DataType) -> bool {
+    found == target || target == DataType::Str || (found == DataType::Int && target == DataType::Float)
+}
+
+/// Infers the description of a column from its cells. The cells which are None are null values.
+pub(crate) fn infer_column<'a, I>(name: &str, cells: I, mode: InferMode) -> ColumnSchema
+where I: Iterator<Item=Option<&'a str>>
+{
+    let mut conteos = [0usize; 5];
+    let mut null_count = 0;
+
+    for cell in cells {
+        match cell {
+            None => null_count += 1,
+            Some(cadena) => conteos[slot(classify(cadena))] += 1,
+        }
+    }
+
+    let total: usize = conteos.iter().sum();
+    let enteros = conteos[slot(DataType::Int)];
+    let flotantes = conteos[slot(DataType::Float)];
+
+    let data_type = if total == 0 {
+        DataType::Str
+    } else if mode == InferMode::PromoteInts && enteros > 0 && flotantes > 0 {
+        DataType::Float
+    } else {
+        ORDEN.iter().zip(conteos.iter()).max_by_key(|(_, conteo)| **conteo).map_or(DataType::Str, |(tipo, _)| *tipo)
+    };
+
+    let validos: usize = ORDEN.iter().zip(conteos.iter())
+        .filter(|(tipo, _)| conforms(**tipo, data_type))
+        .map(|(_, conteo)| conteo)
+        .sum();
+
+    let invalid_ratio = if total == 0 { 0.0 } else { (total - validos) as f64 / total as f64 };
+
+    ColumnSchema {
+        name: String::from(name),
+        data_type,
+        nullable: null_count > 0,
+        null_count,
+        invalid_ratio,
+    }
+}
@@ -0,0 +1,245 @@
+//! Typed columnar storage.
+//!
+//! A `Series<T>` holds the values of a column already parsed to type `T` and a validity bitmap which marks the rows where
+//! the value could not be parsed. A RawFrame parses its cells every time a column is requested, a Series is parsed once
+//! and then it can be reused as many times as needed.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::series::Series;
+//! use std::ffi::OsString;
+//!
+//! let path = OsString::from("./datos_test/test.csv");
+//! let datos = RawFrame::from_os_string(path).unwrap();
+//!
+//! let serie: Series<f64> = datos.series("col_b").unwrap();
+//!
+//! assert_eq!(serie.len(), 5);
+//! assert_eq!(serie.null_count(), 2);
+//! assert_eq!(serie.get(2), Some(&23.0));
+//! assert_eq!(serie.iter_valid().sum::<f64>(), 44.0);
+//! ```
+
+/// A compact vector of bits. In a Series a set bit marks a valid value.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Bitmap {
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl Bitmap {
+    /// Creates an empty Bitmap.
+    pub fn new() -> Bitmap {
+        Bitmap::default()
+    }
+
+    /// Creates a Bitmap of length `len` with all the bits set to `value`.
+    pub fn with_value(len: usize, value: bool) -> Bitmap {
+        let relleno = if value { u64::MAX } else { 0 };
+        let mut bitmap = Bitmap { words: vec![relleno; (len + 63) / 64], len };
+        bitmap.clear_tail();
+        bitmap
+    }
+
+    /// Returns the number of bits.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the Bitmap has no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Appends a bit.
+    pub fn push(&mut self, value: bool) {
+        if self.len % 64 == 0 {
+            self.words.push(0);
+        }
+        let n = self.len;
+        self.len += 1;
+        self.set(n, value);
+    }
+
+    /// Returns the bit in position n or None if it is out of bounds.
+    pub fn get(&self, n: usize) -> Option<bool> {
+        if n >= self.len {
+            return None
+        }
+        Some(self.words[n / 64] & (1 << (n % 64)) != 0)
+    }
+
+    /// Sets the bit in position n. It panics if n is out of bounds.
+    pub fn set(&mut self, n: usize, value: bool) {
+        assert!(n < self.len, "bit {} out of bounds for a bitmap of length {}", n, self.len);
+        if value {
+            self.words[n / 64] |= 1 << (n % 64);
+        } else {
+            self.words[n / 64] &= !(1 << (n % 64));
+        }
+    }
+
+    /// Returns the number of set bits.
+    pub fn count_ones(&self) -> usize {
+        self.words.iter().map(|word| word.count_ones() as usize).sum()
+    }
+
+    /// Returns an iterator over the bits.
+    pub fn iter(&self) -> impl Iterator<Item=bool> + '_ {
+        (0..self.len).map(move |n| self.words[n / 64] & (1 << (n % 64)) != 0)
+    }
+
+    fn clear_tail(&mut self) {
+        if self.len % 64 != 0 {
+            if let Some(last) = self.words.last_mut() {
+                *last &= (1 << (self.len % 64)) - 1;
+            }
+        }
+    }
+}
+
+impl std::iter::FromIterator<bool> for Bitmap {
+    fn from_iter<I: IntoIterator<Item=bool>>(iter: I) -> Bitmap {
+        let mut bitmap = Bitmap::new();
+        for value in iter {
+            bitmap.push(value);
+        }
+        bitmap
+    }
+}
+
+/// A named column of values of type T with a validity bitmap.
+///
+/// The invalid rows hold `T::default()` in the values vector, they must be read through the methods which check the bitmap.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series<T> {
+    name: String,
+    values: Vec<T>,
+    validity: Bitmap,
+}
+
+impl<T: Default> Series<T> {
+    /// Creates a Series from a vec of options. The None values are marked as invalid.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice that holds the name of the Series
+    /// * `values` - A Vec of Option<T> with the values
+    pub fn from_options(name: &str, values: Vec<Option<T>>) -> Series<T> {
+        let validity: Bitmap = values.iter().map(|val| val.is_some()).collect();
+        let values = values.into_iter().map(|val| val.unwrap_or_default()).collect();
+
+        Series { name: String::from(name), values, validity }
+    }
+
+    /// Creates a Series from the cells of a column parsing each cell once. Cells that can not be parsed to T are marked as invalid.
+    pub fn parse<'a, I>(name: &str, cells: I) -> Series<T>
+    where T: std::str::FromStr, I: Iterator<Item=Option<&'a str>>
+    {
+        let mut values = Vec::new();
+        let mut validity = Bitmap::new();
+
+        for cell in cells {
+            match cell.and_then(|cadena| cadena.parse::<T>().ok()) {
+                Some(val) => {
+                    values.push(val);
+                    validity.push(true);
+                },
+                None => {
+                    values.push(T::default());
+                    validity.push(false);
+                },
+            }
+        }
+
+        Series { name: String::from(name), values, validity }
+    }
+}
+
+impl<T> Series<T> {
+    /// Creates a Series where all the values are valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice that holds the name of the Series
+    /// * `values` - A Vec with the values
+    pub fn new(name: &str, values: Vec<T>) -> Series<T> {
+        let validity = Bitmap::with_value(values.len(), true);
+
+        Series { name: String::from(name), values, validity }
+    }
+
+    /// Returns the name of the Series.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Changes the name of the Series.
+    pub fn rename(&mut self, name: &str) {
+        self.name = String::from(name);
+    }
+
+    /// Returns the number of rows, valid or not.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if the Series has no rows.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns the number of invalid rows.
+    pub fn null_count(&self) -> usize {
+        self.len() - self.validity.count_ones()
+    }
+
+    /// Returns the validity bitmap.
+    pub fn validity(&self) -> &Bitmap {
+        &self.validity
+    }
+
+    /// Returns true if the value in position n is valid.
+    pub fn is_valid(&self, n: usize) -> bool {
+        self.validity.get(n).unwrap_or(false)
+    }
+
+    /// Returns the value in position n or None if it is invalid or out of bounds.
+    pub fn get(&self, n: usize) -> Option<&T> {
+        if self.is_valid(n) {
+            self.values.get(n)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator of Option<&T> with all the rows, similar to `RawFrame::col_type`.
+    pub fn iter(&self) -> impl Iterator<Item=Option<&T>> + '_ {
+        self.values.iter().zip(self.validity.iter()).map(|(val, valid)| if valid { Some(val) } else { None })
+    }
+
+    /// Returns an iterator with only the valid values, similar to `RawFrame::col_fil`.
+    pub fn iter_valid(&self) -> impl Iterator<Item=&T> + '_ {
+        self.iter().flatten()
+    }
+
+    /// Returns an iterator with all the rows imputing none_val in the invalid ones, similar to `RawFrame::col_imp`.
+    ///
+    /// # Arguments
+    ///
+    /// * `none_val` - value for imputing the invalid values
+    pub fn iter_imp(&self, none_val: T) -> impl Iterator<Item=T> + '_
+    where T: Clone
+    {
+        self.iter().map(move |val| val.cloned().unwrap_or_else(|| none_val.clone()))
+    }
+
+    /// Returns a Vec of Option<T> with all the rows.
+    pub fn to_options(&self) -> Vec<Option<T>>
+    where T: Clone
+    {
+        self.iter().map(|val| val.cloned()).collect()
+    }
+}
@@ -0,0 +1,140 @@
+//! Sorting of whole records.
+//!
+//! A `SortKey` describes one column used to sort the records of a RawFrame: the type used to compare its values, the
+//! direction and where the null values go. Several keys are applied in order, the second key only decides between records
+//! with the same value in the first key, and so on. The sort is stable.
+//!
+//! Empty fields, missing fields and values which are not possible to parse to the type of the key (including `NaN` for
+//! floats) are null values.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::dataframe::DataType;
+//! use ravencol::sort::SortKey;
+//!
+//! let datos: RawFrame = "region,ventas\nsur,10\nnorte,\nsur,30\nnorte,5\n".parse().unwrap();
+//!
+//! let ordenado = datos.sort_by(&[
+//!     SortKey::new("region", DataType::Str),
+//!     SortKey::new("ventas", DataType::Int).descending(),
+//! ]).unwrap();
+//!
+//! let ventas: Vec<Option<i32>> = ordenado.col_type("ventas").unwrap().collect();
+//! assert_eq!(ventas, vec![Some(5), None, Some(30), Some(10)]);
+//! ```
+
+use crate::RavenError;
+use crate::dataframe::DataType;
+use crate::dates::Date;
+use std::cmp::Ordering;
+
+/// Direction of a sort.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SortOrder {
+    Ascending,
+    Descending,
+}
+
+/// Position of the null values in a sort, it does not depend on the direction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullsOrder {
+    First,
+    Last,
+}
+
+/// A column used to sort the records of a RawFrame.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SortKey {
+    pub column: String,
+    pub order: SortOrder,
+    /// Type used to compare the values, `DataType::Str` compares them lexicographically.
+    pub data_type: DataType,
+    pub nulls: NullsOrder,
+}
+
+impl SortKey {
+    /// Creates an ascending key with the null values at the end.
+    ///
+    /// # Arguments
+    ///
+    /// * `column` - A string slice that holds the name of the column
+    /// * `data_type` - The DataType used to compare the values
+    pub fn new(column: &str, data_type: DataType) -> SortKey {
+        SortKey { column: String::from(column), order: SortOrder::Ascending, data_type, nulls: NullsOrder::Last }
+    }
+
+    /// Sets the direction to descending.
+    pub fn descending(mut self) -> SortKey {
+        self.order = SortOrder::Descending;
+        self
+    }
+
+    /// Puts the null values at the start.
+    pub fn nulls_first(mut self) -> SortKey {
+        self.nulls = NullsOrder::First;
+        self
+    }
+}
+
+/// A value of a record parsed to the type of a key.
+#[derive(Debug, PartialEq, PartialOrd)]
+enum Clave<'a> {
+    Int(i64),
+    Float(f64),
+    Bool(bool),
+    Date(Date),
+    Str(&'a str),
+}
+
+fn clave(cell: Option<&str>, data_type: DataType) -> Option<Clave<'_>> {
+    let cadena = cell.filter(|cadena| !cadena.is_empty())?;
+
+    match data_type {
+        DataType::Int => cadena.parse().ok().map(Clave::Int),
+        DataType::Float => cadena.parse::<f64>().ok().filter(|num| !num.is_nan()).map(Clave::Float),
+        DataType::Bool => cadena.parse().ok().map(Clave::Bool),
+        DataType::Date => cadena.parse().ok().map(Clave::Date),
+        DataType::Str => Some(Clave::Str(cadena)),
+    }
+}
+
+fn compare(a: &Option<Clave>, b: &Option<Clave>, key: &SortKey) -> Ordering {
+    match (a, b) {
+        (None, None) => Ordering::Equal,
+        (None, Some(_)) if key.nulls == NullsOrder::First => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (Some(_), None) if key.nulls == NullsOrder::First => Ordering::Greater,
+        (Some(_), None) => Ordering::Less,
+        (Some(a), Some(b)) => {
+            let orden = a.partial_cmp(b).unwrap_or(Ordering::Equal);
+            match key.order {
+                SortOrder::Ascending => orden,
+                SortOrder::Descending => orden.reverse(),
+            }
+        },
+    }
+}
+
+/// Returns a new RawFrame with the records sorted by the keys.
+pub(crate) fn sort_by(raw: &crate::RawFrame, keys: &[SortKey]) -> Result<crate::RawFrame, RavenError> {
+    let nombres: Vec<&str> = keys.iter().map(|key| key.column.as_str()).collect();
+    let positions = raw.col_positions(&nombres)?;
+
+    let claves: Vec<Vec<Option<Clave>>> = raw.records.iter().map(|record| {
+        keys.iter().zip(positions.iter()).map(|(key, position)| clave(record.get(*position), key.data_type)).collect()
+    }).collect();
+
+    let mut orden: Vec<usize> = (0..raw.records.len()).collect();
+    orden.sort_by(|a, b| {
+        keys.iter().enumerate()
+            .map(|(n, key)| compare(&claves[*a][n], &claves[*b][n], key))
+            .find(|orden| *orden != Ordering::Equal)
+            .unwrap_or(Ordering::Equal)
+    });
+
+    let records = orden.into_iter().map(|n| raw.records[n].clone()).collect();
+
+    Ok(crate::RawFrame { columns: raw.columns.clone(), records })
+}
@@ -0,0 +1,173 @@
+//! Statistical reductions of numeric columns.
+//!
+//! The `Stats` trait extends every iterator of numbers, like the ones returned by `col_fil` and `col_imp`, with the mean,
+//! the variance and standard deviation, the median, quantiles and the mode. The mean and the variance are computed in one
+//! pass with Welford's algorithm, which is numerically stable. The variance and the standard deviation are the sample ones,
+//! divided by `n - 1`.
+//!
+//! `NaN` values are left out of all the reductions. A reduction without values returns None.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::stats::{QuantileMethod, Stats};
+//!
+//! let datos: RawFrame = "x\n1\n2\n2\nNA\n5\n".parse().unwrap();
+//!
+//! assert_eq!(datos.col_fil::<i32>("x").unwrap().mean(), Some(2.5));
+//! assert_eq!(datos.col_fil::<i32>("x").unwrap().median(), Some(2.0));
+//! assert_eq!(datos.col_fil::<i32>("x").unwrap().mode(), Some(2));
+//! assert_eq!(datos.col_fil::<f64>("x").unwrap().quantile(0.9, QuantileMethod::Higher), Some(5.0));
+//! assert_eq!(datos.col_imp::<f64>("x", 0.0).unwrap().variance(), Some(3.5));
+//! ```
+
+/// Numeric types which can be reduced. It is implemented for all the primitive integer and float types.
+pub trait Number: Copy + PartialOrd {
+    /// Returns the value as f64, large 64 and 128 bits integers lose precision.
+    fn to_f64(self) -> f64;
+}
+
+macro_rules! number {
+    ($($tipo:ty),*) => {
+        $(impl Number for $tipo {
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        })*
+    };
+}
+
+number!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize, f32, f64);
+
+/// How to compute a quantile which falls between two values, `i` and `j`, of the sorted data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuantileMethod {
+    /// Linear interpolation between `i` and `j`.
+    Linear,
+    /// The value `i`.
+    Lower,
+    /// The value `j`.
+    Higher,
+    /// The closest value, `i` or `j`, choosing the one with even index on ties.
+    Nearest,
+    /// The mean of `i` and `j`.
+    Midpoint,
+}
+
+/// Running count, mean and sum of squared differences of Welford's algorithm.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct Welford {
+    pub(crate) count: usize,
+    pub(crate) mean: f64,
+    m2: f64,
+}
+
+impl Welford {
+    /// Adds a value, `NaN` values are left out.
+    pub(crate) fn push(&mut self, value: f64) {
+        if value.is_nan() {
+            return
+        }
+
+        self.count += 1;
+        let delta = value - self.mean;
+        self.mean += delta / self.count as f64;
+        self.m2 += delta * (value - self.mean);
+    }
+
+    /// Returns the sample variance, None with less than two values.
+    pub(crate) fn variance(&self) -> Option<f64> {
+        if self.count > 1 {
+            Some(self.m2 / (self.count - 1) as f64)
+        } else {
+            None
+        }
+    }
+}
+
+/// Returns the quantile of values sorted in ascending order, None if there are no values or `q` is not between 0 and 1.
+pub(crate) fn quantile_sorted(ordenados: &[f64], q: f64, method: QuantileMethod) -> Option<f64> {
+    if ordenados.is_empty() || !(0.0..=1.0).contains(&q) {
+        return None
+    }
+
+    let rango = q * (ordenados.len() - 1) as f64;
+    let i = rango.floor() as usize;
+    let j = rango.ceil() as usize;
+    let fraccion = rango - i as f64;
+
+    let valor = match method {
+        QuantileMethod::Linear => ordenados[i] + (ordenados[j] - ordenados[i]) * fraccion,
+        QuantileMethod::Lower => ordenados[i],
+        QuantileMethod::Higher => ordenados[j],
+        QuantileMethod::Nearest if fraccion < 0.5 || (fraccion == 0.5 && i % 2 == 0) => ordenados[i],
+        QuantileMethod::Nearest => ordenados[j],
+        QuantileMethod::Midpoint => (ordenados[i] + ordenados[j]) / 2.0,
+    };
+
+    Some(valor)
+}
+
+/// Statistical reductions for iterators of numbers.
+pub trait Stats<T: Number>: Iterator<Item=T> + Sized {
+    /// Returns the arithmetic mean.
+    fn mean(self) -> Option<f64> {
+        let mut welford = Welford::default();
+        self.for_each(|valor| welford.push(valor.to_f64()));
+
+        if welford.count > 0 { Some(welford.mean) } else { None }
+    }
+
+    /// Returns the sample variance, None with less than two values.
+    fn variance(self) -> Option<f64> {
+        let mut welford = Welford::default();
+        self.for_each(|valor| welford.push(valor.to_f64()));
+
+        welford.variance()
+    }
+
+    /// Returns the sample standard deviation, None with less than two values.
+    fn std(self) -> Option<f64> {
+        self.variance().map(f64::sqrt)
+    }
+
+    /// Returns the median, the mean of the two central values when their number is even.
+    fn median(self) -> Option<f64> {
+        self.quantile(0.5, QuantileMethod::Linear)
+    }
+
+    /// Returns the quantile `q`, None if `q` is not between 0 and 1.
+    ///
+    /// # Arguments
+    ///
+    /// * `q` - The quantile, 0.25 is the first quartile
+    /// * `method` - The QuantileMethod used when the quantile falls between two values
+    fn quantile(self, q: f64, method: QuantileMethod) -> Option<f64> {
+        let mut ordenados: Vec<f64> = self.map(Number::to_f64).filter(|valor| !valor.is_nan()).collect();
+        ordenados.sort_by(|a, b| a.total_cmp(b));
+
+        quantile_sorted(&ordenados, q, method)
+    }
+
+    /// Returns the most frequent value, the smallest one on ties.
+    fn mode(self) -> Option<T> {
+        let mut valores: Vec<T> = self.filter(|valor| !valor.to_f64().is_nan()).collect();
+        valores.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+
+        let mut moda: Option<(T, usize)> = None;
+        let mut inicio = 0;
+        for n in 1..=valores.len() {
+            if n == valores.len() || valores[n] != valores[inicio] {
+                if moda.map_or(true, |(_, conteo)| n - inicio > conteo) {
+                    moda = Some((valores[inicio], n - inicio));
+                }
+                inicio = n;
+            }
+        }
+
+        moda.map(|(valor, _)| valor)
+    }
+}
+
+impl<T: Number, I: Iterator<Item=T>> Stats<T> for I {}
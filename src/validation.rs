@@ -0,0 +1,276 @@
+//! Validation of RawFrames against a declared schema.
+//!
+//! A `DeclaredSchema` lists the expected columns with their type, if they can hold null values and extra rules: an allowed
+//! range, a regular expression or a set of allowed values. Validating a RawFrame checks every record and produces a
+//! `ValidationReport` with the row, column, value and rule of each violation. The `ValidationMode` decides if the rows with
+//! violations are rejected, if the invalid values are replaced by null values or if the validation stops at the first violation.
+//!
+//! Null values are empty fields and missing fields.
+//!
+//! # Examples
+//!
+//! ```
+//! use ravencol::RawFrame;
+//! use ravencol::dataframe::DataType;
+//! use ravencol::validation::{ColumnSpec, DeclaredSchema, ValidationMode};
+//!
+//! let datos: RawFrame = "estado,poblacion\nCDMX,9209944\nJalisco,-5\nMarte,100\n".parse().unwrap();
+//!
+//! let schema = DeclaredSchema::new()
+//!     .column(ColumnSpec::new("estado", DataType::Str).one_of(&["CDMX", "Jalisco"]))
+//!     .column(ColumnSpec::new("poblacion", DataType::Int).range(Some(0.0), None));
+//!
+//! let (validos, report) = datos.validate(&schema, ValidationMode::Reject).unwrap();
+//!
+//! assert_eq!(validos.records.len(), 1);
+//! assert_eq!(report.violations.len(), 2);
+//! assert_eq!(report.violations[0].column, "poblacion");
+//! assert_eq!(report.violations[0].value, "-5");
+//! ```
+
+use crate::RavenError;
+use crate::dataframe::DataType;
+use std::fmt;
+
+/// A rule that the values of a column must satisfy.
+#[derive(Debug, Clone)]
+pub enum Rule {
+    /// The value can not be null.
+    NotNull,
+    /// The value must be parseable to the type.
+    Type(DataType),
+    /// The value parsed as a number must be inside the range, both limits are inclusive.
+    Range { min: Option<f64>, max: Option<f64> },
+    /// The value must match the regular expression.
+    Pattern(regex::Regex),
+    /// The value must be one of the values of the set.
+    OneOf(Vec<String>),
+}
+
+impl fmt::Display for Rule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Rule::NotNull => write!(f, "not null"),
+            Rule::Type(data_type) => write!(f, "type {}", data_type),
+            Rule::Range { min, max } => {
+                let min = min.map_or(String::from("-inf"), |val| val.to_string());
+                let max = max.map_or(String::from("inf"), |val| val.to_string());
+                write!(f, "range [{}, {}]", min, max)
+            },
+            Rule::Pattern(regex) => write!(f, "pattern {}", regex.as_str()),
+            Rule::OneOf(valores) => write!(f, "one of {}", valores.join(", ")),
+        }
+    }
+}
+
+impl Rule {
+    /// Returns true if the non null value satisfies the rule.
+    fn check(&self, cadena: &str) -> bool {
+        match self {
+            Rule::NotNull => true,
+            Rule::Type(data_type) => conforms(cadena, *data_type),
+            Rule::Range { min, max } => match cadena.parse::<f64>() {
+                Ok(num) => min.map_or(true, |min| num >= min) && max.map_or(true, |max| num <= max),
+                Err(_) => false,
+            },
+            Rule::Pattern(regex) => regex.is_match(cadena),
+            Rule::OneOf(valores) => valores.iter().any(|valor| valor == cadena),
+        }
+    }
+}
+
+/// Returns true if the value can be parsed to the type.
+fn conforms(cadena: &str, data_type: DataType) -> bool {
+    match data_type {
+        DataType::Int => cadena.parse::<i64>().is_ok(),
+        DataType::Float => cadena.parse::<f64>().is_ok(),
+        DataType::Bool => cadena.parse::<bool>().is_ok(),
+        DataType::Date => cadena.parse::<crate::dates::Date>().is_ok(),
+        DataType::Str => true,
+    }
+}
+
+/// The declaration of a column: its name, type, nullability and rules.
+#[derive(Debug, Clone)]
+pub struct ColumnSpec {
+    pub name: String,
+    pub data_type: DataType,
+    pub nullable: bool,
+    pub rules: Vec<Rule>,
+}
+
+impl ColumnSpec {
+    /// Creates the declaration of a nullable column of the given type without extra rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - A string slice that holds the name of the column
+    /// * `data_type` - The DataType that all the non null values must have
+    pub fn new(name: &str, data_type: DataType) -> ColumnSpec {
+        ColumnSpec { name: String::from(name), data_type, nullable: true, rules: Vec::new() }
+    }
+
+    /// Sets if the column can hold null values.
+    pub fn nullable(mut self, nullable: bool) -> ColumnSpec {
+        self.nullable = nullable;
+        self
+    }
+
+    /// Adds an inclusive range for the values parsed as numbers. None means the range has no limit on that side.
+    pub fn range(mut self, min: Option<f64>, max: Option<f64>) -> ColumnSpec {
+        self.rules.push(Rule::Range { min, max });
+        self
+    }
+
+    /// Adds a regular expression that the values must match. It returns an error if the expression is not valid.
+    pub fn pattern(mut self, pattern: &str) -> Result<ColumnSpec, RavenError> {
+        let regex = regex::Regex::new(pattern).map_err(|err| RavenError::InvalidPattern {
+            pattern: String::from(pattern),
+            error: err.to_string(),
+        })?;
+        self.rules.push(Rule::Pattern(regex));
+        Ok(self)
+    }
+
+    /// Adds the set of allowed values.
+    pub fn one_of(mut self, valores: &[&str]) -> ColumnSpec {
+        self.rules.push(Rule::OneOf(valores.iter().map(|valor| String::from(*valor)).collect()));
+        self
+    }
+
+    /// Returns the first rule violated by a cell, or None if the cell is valid.
+    fn violated(&self, cell: Option<&str>) -> Option<Rule> {
+        let cadena = match cell.filter(|cadena| !cadena.is_empty()) {
+            None if self.nullable => return None,
+            None => return Some(Rule::NotNull),
+            Some(cadena) => cadena,
+        };
+
+        let tipo = Rule::Type(self.data_type);
+        std::iter::once(&tipo).chain(self.rules.iter()).find(|rule| !rule.check(cadena)).cloned()
+    }
+}
+
+/// A schema declared by the user to validate RawFrames.
+#[derive(Debug, Clone, Default)]
+pub struct DeclaredSchema {
+    pub columns: Vec<ColumnSpec>,
+}
+
+impl DeclaredSchema {
+    /// Creates an empty DeclaredSchema.
+    pub fn new() -> DeclaredSchema {
+        DeclaredSchema::default()
+    }
+
+    /// Adds the declaration of a column.
+    pub fn column(mut self, spec: ColumnSpec) -> DeclaredSchema {
+        self.columns.push(spec);
+        self
+    }
+}
+
+/// What to do with the rows that have violations.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ValidationMode {
+    /// The rows with violations are removed.
+    Reject,
+    /// The invalid values are replaced by null values. Rows with invalid values in not nullable columns are removed.
+    CoerceNull,
+    /// The validation stops with an error at the first violation.
+    Abort,
+}
+
+/// A value that does not satisfy a rule of the declared schema.
+#[derive(Debug, Clone)]
+pub struct Violation {
+    /// Index of the row in the validated RawFrame.
+    pub row: usize,
+    /// Line of the file where the row starts, or 0 if the row was not read from a file.
+    pub line: u64,
+    /// Name of the column.
+    pub column: String,
+    /// The invalid value. Missing fields are empty strings.
+    pub value: String,
+    /// The rule that the value does not satisfy.
+    pub rule: Rule,
+}
+
+/// Report of a validation with all the violations found.
+#[derive(Debug, Clone, Default)]
+pub struct ValidationReport {
+    pub violations: Vec<Violation>,
+    /// Number of rows removed from the result.
+    pub rejected_rows: usize,
+}
+
+impl ValidationReport {
+    /// Returns true if no violations were found.
+    pub fn is_clean(&self) -> bool {
+        self.violations.is_empty()
+    }
+}
+
+/// Validates the records of a RawFrame with the declared schema. It returns a new RawFrame with the rows and values kept by the mode.
+pub(crate) fn validate(raw: &crate::RawFrame, schema: &DeclaredSchema, mode: ValidationMode) -> Result<(crate::RawFrame, ValidationReport), RavenError> {
+    let nombres: Vec<&str> = schema.columns.iter().map(|spec| spec.name.as_str()).collect();
+    let positions = raw.col_positions(&nombres)?;
+
+    let mut report = ValidationReport::default();
+    let mut records = Vec::with_capacity(raw.records.len());
+
+    for (row, record) in raw.records.iter().enumerate() {
+        let mut rechazar = false;
+        let mut nulos: Vec<usize> = Vec::new();
+
+        for (spec, position) in schema.columns.iter().zip(positions.iter()) {
+            let cell = record.get(*position);
+
+            let rule = match spec.violated(cell) {
+                None => continue,
+                Some(rule) => rule,
+            };
+
+            let violation = Violation {
+                row,
+                line: record.position().map_or(0, |pos| pos.line()),
+                column: spec.name.clone(),
+                value: String::from(cell.unwrap_or("")),
+                rule,
+            };
+
+            match mode {
+                ValidationMode::Abort => {
+                    return Err(RavenError::ValidationFailed {
+                        line: violation.line,
+                        column: violation.column,
+                        value: violation.value,
+                        rule: violation.rule.to_string(),
+                    })
+                },
+                ValidationMode::Reject => rechazar = true,
+                ValidationMode::CoerceNull if spec.nullable => nulos.push(*position),
+                ValidationMode::CoerceNull => rechazar = true,
+            }
+
+            report.violations.push(violation);
+        }
+
+        if rechazar {
+            report.rejected_rows += 1;
+            continue;
+        }
+
+        if nulos.is_empty() {
+            records.push(record.clone());
+        } else {
+            let mut nuevo: csv::StringRecord = record.iter().enumerate()
+                .map(|(n, cadena)| if nulos.contains(&n) { "" } else { cadena })
+                .collect();
+            nuevo.set_position(record.position().cloned());
+            records.push(nuevo);
+        }
+    }
+
+    Ok((crate::RawFrame { columns: raw.columns.clone(), records }, report))
+}
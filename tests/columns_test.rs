@@ -1,3 +1,5 @@
+#![allow(clippy::let_and_return)]
+
 use ravencol::RawFrame;
 use ravencol::Datum;
 use std::ffi::OsString;
@@ -126,8 +128,24 @@ fn fil_col_test_b(){
     assert_eq!(iter.next(), None);
 }
 
+#[test]
+fn datum_detect_test(){
+    let datos: RawFrame = "id,valor\n1,3000000000\n2,true\n3,\n4,2020-03-15\n5,2020-03-15T10:30:00\n6,hola\n7\n".parse().unwrap();
+
+    let col: Vec<Datum> = datos.column("valor").unwrap().collect();
+
+    assert_eq!(col[0], Datum::Int64(3000000000));
+    assert_eq!(col[1], Datum::Bool(true));
+    assert_eq!(col[2], Datum::Empty);
+    assert_eq!(col[3], Datum::Date("2020-03-15".parse().unwrap()));
+    assert_eq!(col[4], Datum::DateTime("2020-03-15T10:30:00".parse().unwrap()));
+    assert_eq!(col[5], Datum::NotNumber("hola"));
+    assert_eq!(col[6], Datum::None);
+    assert!(col[2].is_null() && col[6].is_null());
+}
+
 fn get_data() -> ravencol::RawFrame {
     let path = OsString::from("./datos_test/test.csv");
     let datos = RawFrame::from_os_string(path).unwrap();
     datos
-}
\ No newline at end of file
+}
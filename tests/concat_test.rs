@@ -0,0 +1,29 @@
+use ravencol::{ConcatMode, RawFrame};
+
+#[test]
+fn concat_by_name_test(){
+    let mut base: RawFrame = "a,b\n1,2\n".parse().unwrap();
+    let cola: RawFrame = "b,c,a\n3,x,4\n".parse().unwrap();
+    base.concat_by_name(cola, ConcatMode::Intersection).unwrap();
+    assert_eq!(base.columns.iter().collect::<Vec<&str>>(), vec!["a", "b"]);
+    assert_eq!(base.records[1].iter().collect::<Vec<&str>>(), vec!["4", "3"]);
+
+    let mut base: RawFrame = "a,b\n1,2\n".parse().unwrap();
+    let cola: RawFrame = "b,a\n3,4\n".parse().unwrap();
+    base.concat_by_name(cola, ConcatMode::Strict).unwrap();
+    let col_a: Vec<i32> = base.col_fil("a").unwrap().collect();
+    assert_eq!(col_a, vec![1, 4]);
+
+    let cola: RawFrame = "b,c\n3,4\n".parse().unwrap();
+    match base.concat_by_name(cola, ConcatMode::Strict) {
+        Err(ravencol::RavenError::ColumnSetMismatch { missing, extra }) => {
+            assert_eq!(missing, vec!["a"]);
+            assert_eq!(extra, vec!["c"]);
+        },
+        otro => panic!("unexpected result {:?}", otro),
+    }
+    assert_eq!(base.records.len(), 2);
+
+    let cola: RawFrame = "a,a\n3,4\n".parse().unwrap();
+    assert!(base.concat_by_name(cola, ConcatMode::Union).is_err());
+}
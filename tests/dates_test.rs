@@ -0,0 +1,94 @@
+use ravencol::{Datum, RawFrame};
+use ravencol::dates::{Date, DateFormat, DateTime};
+
+#[test]
+fn epoch_days_test(){
+    let fechas = ["1970-01-01", "2000-02-29", "1969-12-31", "1900-03-01", "2024-12-31"];
+
+    for fecha in fechas.iter() {
+        let fecha: Date = fecha.parse().unwrap();
+        assert_eq!(Date::from_days_since_epoch(fecha.days_since_epoch()), fecha);
+    }
+
+    assert_eq!("1970-01-02".parse::<Date>().unwrap().days_since_epoch(), 1);
+    assert_eq!("1969-12-31".parse::<Date>().unwrap().days_since_epoch(), -1);
+}
+
+#[test]
+fn format_specifiers_test(){
+    let formato = DateFormat::new("%m/%d/%y %I:%M %p").unwrap();
+    let fecha = formato.parse_datetime("3/15/20 10:30 PM").unwrap();
+    assert_eq!(fecha.to_string(), "2020-03-15T22:30:00");
+    assert_eq!(formato.parse_datetime("3/15/20 12:05 am").unwrap().hour(), 0);
+    assert_eq!(formato.parse_datetime("3/15/20 13:05 PM"), None);
+
+    let formato = DateFormat::new("%b %e, %Y").unwrap();
+    assert_eq!(formato.parse_date("Mar  5, 2020"), Date::new(2020, 3, 5));
+    assert_eq!(formato.parse_date("Dic 24, 2020"), Date::new(2020, 12, 24));
+
+    let formato = DateFormat::new("%FT%T.%f%z").unwrap();
+    let fecha = formato.parse_datetime("2020-03-15T10:30:00.25+05:30").unwrap();
+    assert_eq!(fecha.nanosecond(), 250_000_000);
+    assert_eq!(fecha.offset(), Some(19800));
+
+    assert_eq!(DateFormat::new("%d/%m/%Y").unwrap().parse_date("31/02/2020"), None);
+    assert_eq!(DateFormat::new("%d/%m/%Y").unwrap().parse_date("15/03/2020 extra"), None);
+}
+
+#[test]
+fn invalid_format_test(){
+    let error = DateFormat::new("%d/%Q").unwrap_err();
+    assert!(matches!(error, ravencol::RavenError::InvalidDateFormat { .. }));
+    assert!(DateFormat::new("%Y%").is_err());
+}
+
+#[test]
+fn offsets_test(){
+    let local: DateTime = "2020-12-31T20:00:00-06:00".parse().unwrap();
+    let utc = local.to_utc();
+
+    assert_eq!(utc.date(), Date::new(2021, 1, 1).unwrap());
+    assert_eq!(utc.hour(), 2);
+    assert_eq!(utc, local);
+    assert_eq!(DateTime::from_timestamp(local.timestamp(), 0, Some(-21600)).unwrap().to_string(), local.to_string());
+
+    let antes: DateTime = "2020-12-31T21:00:00-06:00".parse().unwrap();
+    let despues: DateTime = "2021-01-01T03:30:00Z".parse().unwrap();
+    assert!(antes < despues);
+
+    assert_eq!(local.to_offset(i32::MIN), None);
+    assert_eq!(local.to_offset(86400), None);
+    assert!(local.to_offset(-86399).is_some());
+}
+
+#[test]
+fn multibyte_separator_test(){
+    assert!("2020-03-15é12345".parse::<DateTime>().is_err());
+    assert!("2020-03-15ñ10:30".parse::<DateTime>().is_err());
+
+    let datos: RawFrame = "a\n2020-03-15é12345\n".parse().unwrap();
+    let columna: Vec<Datum> = datos.column("a").unwrap().collect();
+    assert_eq!(columna, vec![Datum::NotNumber("2020-03-15é12345")]);
+}
+
+#[test]
+fn date_columns_test(){
+    let datos: RawFrame = "fecha,a,b\n2020-03-02 08:00,1,x\n2020-03-01 09:15,2,3\nmal,3,4\n".parse().unwrap();
+
+    let fechas: Vec<Option<DateTime>> = datos.col_date("fecha", None).unwrap().collect();
+    assert_eq!(fechas[0].unwrap().minute(), 0);
+    assert_eq!(fechas[2], None);
+
+    let filas: Vec<(DateTime, Vec<i32>)> = datos.slice_col_imp_date("fecha", vec!["a", "b"], vec![0, 0], None).unwrap().collect();
+    assert_eq!(filas.len(), 2);
+    assert_eq!(filas[0].1, vec![2, 3]);
+    assert_eq!(filas[1].1, vec![1, 0]);
+
+    let filas: Vec<(Date, Vec<i32>)> = datos.slice_col_fil_date("fecha", vec!["a", "b"], Some("%Y-%m-%d %H:%M")).unwrap().collect();
+    assert_eq!(filas, vec![(Date::new(2020, 3, 1).unwrap(), vec![2, 3])]);
+
+    let pares: Vec<(Date, f64)> = datos.pair_col_imp_date("fecha", "b", Some("%Y-%m-%d %H:%M"), -1.0).unwrap().collect();
+    assert_eq!(pares[1], (Date::new(2020, 3, 2).unwrap(), -1.0));
+
+    assert!(datos.col_date::<Date>("fecha", Some("%d/%K")).is_err());
+}
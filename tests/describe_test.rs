@@ -0,0 +1,35 @@
+use ravencol::RawFrame;
+use ravencol::dataframe::DataType;
+
+#[test]
+fn describe_test(){
+    let datos: RawFrame = "id,monto,fecha,nombre\n1,10,2020-01-01,ana\n2,x,2020-01-02,luis\n3,,fecha,ana\n4,40,2020-01-04,\n".parse().unwrap();
+    let resumen = datos.describe_with(2);
+
+    let monto = resumen.get("monto").unwrap();
+    assert_eq!(monto.data_type, DataType::Int);
+    assert_eq!((monto.count, monto.null_count, monto.invalid_count), (3, 1, 1));
+    assert_eq!(monto.mean, Some(25.0));
+    assert_eq!(monto.std, Some(450.0f64.sqrt()));
+    assert_eq!((monto.min, monto.q1, monto.median, monto.q3, monto.max), (Some(10.0), Some(17.5), Some(25.0), Some(32.5), Some(40.0)));
+
+    let fecha = resumen.get("fecha").unwrap();
+    assert_eq!(fecha.data_type, DataType::Date);
+    assert_eq!(fecha.invalid_count, 1);
+    assert_eq!(fecha.mean, None);
+
+    let nombre = resumen.get("nombre").unwrap();
+    assert_eq!(nombre.n_unique, 2);
+    assert_eq!(nombre.top, vec![(String::from("ana"), 2), (String::from("luis"), 1)]);
+
+    let frame = resumen.to_rawframe();
+    assert_eq!(frame.records.len(), 4);
+    assert_eq!(frame.col_index("25%"), Some(8));
+    assert_eq!(frame.records[3].get(13), Some("ana (2), luis (1)"));
+    assert_eq!(frame.records[1].get(6), Some(450.0f64.sqrt().to_string().as_str()));
+
+    let tabla = resumen.to_string();
+    assert_eq!(tabla.lines().count(), 5);
+    assert!(tabla.lines().nth(2).unwrap().starts_with("monto   int"));
+    assert!(tabla.contains("21.2132"));
+}
@@ -0,0 +1,79 @@
+use ravencol::RawFrame;
+use ravencol::RavenError;
+use ravencol::errors::Language;
+use std::ffi::OsString;
+
+#[test]
+fn column_not_found_test(){
+    let datos = get_data();
+
+    let error = datos.col_fil::<i32>("col_z").err().unwrap();
+
+    match &error {
+        RavenError::ColumnNotFound { name, available } => {
+            assert_eq!(name,"col_z");
+            assert_eq!(available,&vec![String::from("col_a"),String::from("col_b")]);
+        },
+        _ => panic!("unexpected error {:?}", error),
+    }
+
+    assert_eq!(error.localized(Language::English),"column col_z does not exist, available columns: col_a, col_b");
+    assert_eq!(error.localized(Language::Spanish),"No existe la columna col_z, columnas disponibles: col_a, col_b");
+}
+
+#[test]
+fn schema_mismatch_test(){
+    let mut datos = get_data();
+    let otros: RawFrame = "col_a\n1\n".parse().unwrap();
+
+    match datos.concat(otros) {
+        Err(RavenError::SchemaMismatch { expected, found }) => {
+            assert_eq!(expected.len(),2);
+            assert_eq!(found,vec![String::from("col_a")]);
+        },
+        _ => panic!("concat should fail"),
+    }
+}
+
+#[test]
+fn empty_column_test(){
+    let datos: RawFrame = "col_a\nx\ny\n".parse().unwrap();
+
+    assert!(matches!(datos.max_num_fil::<i32>("col_a"), Err(RavenError::EmptyColumn { .. })));
+}
+
+#[test]
+fn io_error_test(){
+    let path = OsString::from("./datos_test/no_existe.csv");
+
+    assert!(matches!(RawFrame::from_os_string(path), Err(RavenError::Io(_))));
+}
+
+#[test]
+fn columns_not_found_test(){
+    let datos = get_data();
+
+    match datos.slice_col_fil::<f64>(vec!["col_a","col_x","col_b","col_y"]) {
+        Err(RavenError::ColumnsNotFound { names, .. }) => {
+            assert_eq!(names,vec![String::from("col_x"),String::from("col_y")]);
+        },
+        _ => panic!("slice_col_fil should fail"),
+    }
+
+    assert!(matches!(datos.slice_col_imp(vec!["col_x"],vec![0.0]), Err(RavenError::ColumnsNotFound { .. })));
+    assert!(matches!(datos.column_major_vector(vec!["col_a","col_x"],vec![0.0,0.0]), Err(RavenError::ColumnsNotFound { .. })));
+    assert!(matches!(datos.pair_col_fil_sorted::<f64>("col_x","col_y"), Err(RavenError::ColumnsNotFound { names, .. }) if names.len() == 2));
+    assert!(matches!(datos.pair_col_imp_sorted("col_a","col_y",0.0,0.0), Err(RavenError::ColumnsNotFound { .. })));
+}
+
+#[test]
+fn length_mismatch_test(){
+    let datos = get_data();
+
+    assert!(matches!(datos.row_major_vector(vec!["col_a","col_b"],vec![0.0]), Err(RavenError::LengthMismatch { expected: 2, found: 1 })));
+}
+
+fn get_data() -> ravencol::RawFrame {
+    let path = OsString::from("./datos_test/test.csv");
+    RawFrame::from_os_string(path).unwrap()
+}
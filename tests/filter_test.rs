@@ -0,0 +1,40 @@
+use ravencol::{Datum, RawFrame};
+
+#[test]
+fn filter_test(){
+    let datos = get_data();
+
+    let occidente = datos.filter(|row| row.get("region") == Some("occidente"));
+
+    assert_eq!(occidente.records.len(), 2);
+    assert_eq!(occidente.columns, datos.columns);
+
+    let nombres: Vec<String> = occidente.col_fil("estado").unwrap().collect();
+    let poblacion: Vec<i64> = occidente.col_fil("poblacion").unwrap().collect();
+    assert_eq!(nombres, vec!["Colima", "Jalisco"]);
+    assert_eq!(poblacion, vec![731391, 8348151]);
+
+    let sin_dato = datos.filter(|row| row.datum("poblacion") == Datum::NotNumber("NA"));
+    assert_eq!(sin_dato.records[0].get(0), Some("Oaxaca"));
+
+    let ninguno = datos.filter(|row| row.get("otra").is_some());
+    assert!(ninguno.records.is_empty());
+}
+
+#[test]
+fn filter_by_test(){
+    let datos = get_data();
+
+    let grandes = datos.filter_by("poblacion", |pob: Option<i64>| pob.map_or(false, |pob| pob > 1000000)).unwrap();
+    let nombres: Vec<String> = grandes.col_fil("estado").unwrap().collect();
+    assert_eq!(nombres, vec!["CDMX", "Jalisco"]);
+
+    let nulos = datos.filter_by("poblacion", |pob: Option<i64>| pob.is_none()).unwrap();
+    assert_eq!(nulos.records.len(), 1);
+
+    assert!(datos.filter_by("otra", |val: Option<i64>| val.is_some()).is_err());
+}
+
+fn get_data() -> RawFrame {
+    "estado,region,poblacion\nCDMX,centro,9209944\nColima,occidente,731391\nJalisco,occidente,8348151\nOaxaca,sur,NA\n".parse().unwrap()
+}
@@ -0,0 +1,60 @@
+use ravencol::RawFrame;
+use ravencol::RavenError;
+use ravencol::floats::NanPolicy;
+
+#[test]
+fn extent_float_test(){
+    let datos = get_data();
+
+    let extent: (f64,f64) = datos.extent_float_fil("x", NanPolicy::Ignore).unwrap();
+    assert_eq!(extent,(-1.0,3.5));
+
+    let extent: (f32,f32) = datos.extent_float_fil("x", NanPolicy::First).unwrap();
+    assert!(extent.0.is_nan());
+    assert_eq!(extent.1,3.5);
+
+    let maximo: f64 = datos.max_float_fil("x", NanPolicy::Last).unwrap();
+    assert!(maximo.is_nan());
+
+    let minimo: f64 = datos.min_float_fil("x", NanPolicy::Last).unwrap();
+    assert_eq!(minimo,-1.0);
+}
+
+#[test]
+fn extent_float_error_test(){
+    let datos = get_data();
+
+    assert!(matches!(datos.extent_float_fil::<f64>("x", NanPolicy::Error), Err(RavenError::NanFound { .. })));
+    assert!(matches!(datos.extent_float_fil::<f64>("z", NanPolicy::Ignore), Err(RavenError::EmptyColumn { .. })));
+}
+
+#[test]
+fn sorted_float_test(){
+    let datos = get_data();
+
+    let pairs: Vec<(f64,f64)> = datos.pair_col_fil_sorted_float("x","y",NanPolicy::Ignore).unwrap().collect();
+    assert_eq!(pairs,vec![(-1.0,4.0),(2.0,1.0),(3.5,2.0)]);
+
+    let pairs: Vec<(f64,f64)> = datos.pair_col_fil_sorted_float("x","y",NanPolicy::First).unwrap().collect();
+    assert!(pairs[0].0.is_nan());
+    assert_eq!(pairs[1],(-1.0,4.0));
+
+    let pairs: Vec<(f64,f64)> = datos.pair_col_imp_sorted_float("x","y",0.0,0.0,NanPolicy::Last).unwrap().collect();
+    assert_eq!(pairs.len(),4);
+    assert!(pairs[3].0.is_nan());
+
+    assert!(datos.pair_col_fil_sorted_float::<f64>("x","y",NanPolicy::Error).is_err());
+}
+
+#[test]
+fn sorted_nan_no_panic_test(){
+    let datos = get_data();
+
+    let pairs: Vec<(f64,f64)> = datos.pair_col_fil_sorted("x","y").unwrap().collect();
+    assert_eq!(pairs.len(),4);
+    assert!(pairs[3].0.is_nan());
+}
+
+fn get_data() -> RawFrame {
+    "x,y,z\n2,1,a\nNaN,3,b\n-1,4,c\n3.5,2,d\n".parse().unwrap()
+}
@@ -0,0 +1,50 @@
+use ravencol::RawFrame;
+use ravencol::aggregate::Aggregation;
+
+#[test]
+fn group_by_test(){
+    let datos = get_data();
+
+    let resumen = datos.group_by(&["region"]).unwrap()
+        .custom("tienda", "tiendas", |valores| Some(valores.join("|")))
+        .agg(&[
+            ("ventas", Aggregation::Count),
+            ("ventas", Aggregation::Mean),
+            ("ventas", Aggregation::Median),
+            ("ventas", Aggregation::First),
+            ("tienda", Aggregation::NUnique),
+        ]).unwrap();
+
+    assert_eq!(resumen.columns.iter().collect::<Vec<&str>>(), vec!["region", "ventas_count", "ventas_mean", "ventas_median", "ventas_first", "tienda_n_unique", "tiendas"]);
+    assert_eq!(resumen.records.len(), 3);
+    assert_eq!(resumen.records[0].iter().collect::<Vec<&str>>(), vec!["sur", "2", "20", "20", "10", "2", "a|b|a"]);
+    assert_eq!(resumen.records[1].iter().collect::<Vec<&str>>(), vec!["norte", "1", "4", "4", "4", "1", "b|b"]);
+    assert_eq!(resumen.records[2].iter().collect::<Vec<&str>>(), vec!["", "1", "7", "7", "7", "1", "c"]);
+
+    let vacio = datos.group_by(&["region"]).unwrap().agg(&[("ventas", Aggregation::Min)]).unwrap();
+    let minimos: Vec<Option<f64>> = vacio.col_type("ventas_min").unwrap().collect();
+    assert_eq!(minimos, vec![Some(10.0), Some(4.0), Some(7.0)]);
+
+    let sin_valores: RawFrame = "k,v\na,\nb,1\n".parse().unwrap();
+    let sumas = sin_valores.group_by(&["k"]).unwrap().agg(&[("v", Aggregation::Sum), ("v", Aggregation::Count)]).unwrap();
+    assert_eq!(sumas.records[0].iter().collect::<Vec<&str>>(), vec!["a", "", "0"]);
+    assert_eq!(sumas.records[1].iter().collect::<Vec<&str>>(), vec!["b", "1", "1"]);
+
+    let codigos: RawFrame = "region,tienda,clave\nsur,centro,007\nnorte,,010\nsur,playa,\nnorte,puerto,x\n".parse().unwrap();
+    let extremos = codigos.group_by(&["region"]).unwrap()
+        .agg(&[("tienda", Aggregation::First), ("tienda", Aggregation::Last), ("clave", Aggregation::First), ("clave", Aggregation::Last)])
+        .unwrap();
+    assert_eq!(extremos.records[0].iter().collect::<Vec<&str>>(), vec!["sur", "centro", "playa", "007", "007"]);
+    assert_eq!(extremos.records[1].iter().collect::<Vec<&str>>(), vec!["norte", "puerto", "puerto", "010", "x"]);
+
+    assert!(datos.group_by(&["zona"]).is_err());
+    assert!(datos.group_by(&["region"]).unwrap().agg(&[("otra", Aggregation::Sum)]).is_err());
+    assert!(datos.group_by(&["region"]).unwrap().custom("ventas", "region", |_| None).agg(&[]).is_err());
+
+    let tipado = resumen.select(&["region", "ventas_mean"]).unwrap();
+    assert_eq!(tipado.col_fil::<f64>("ventas_mean").unwrap().sum::<f64>(), 31.0);
+}
+
+fn get_data() -> RawFrame {
+    "region,tienda,ventas\nsur,a,10\nnorte,b,4\nsur,b,30\nnorte,b,\nsur,a,x\n,c,7\n".parse().unwrap()
+}
@@ -0,0 +1,46 @@
+use ravencol::RawFrame;
+use ravencol::dataframe::DataType;
+use ravencol::join::{JoinOptions, JoinType};
+
+#[test]
+fn join_test(){
+    let (ventas, catalogo) = get_data();
+
+    let inner = ventas.join(&catalogo, &["anio", "clave"], JoinType::Inner).unwrap();
+    assert_eq!(inner.columns.iter().collect::<Vec<&str>>(), vec!["anio", "clave", "monto_left", "nombre", "monto_right"]);
+    assert_eq!(inner.records.len(), 3);
+    assert_eq!(inner.records[0].iter().collect::<Vec<&str>>(), vec!["2020", "2", "5", "dos", "200"]);
+
+    let opciones = JoinOptions::new().key_type("clave", DataType::Int).suffixes("", "_cat");
+    let left = ventas.join_with(&catalogo, &["anio", "clave"], JoinType::Left, &opciones).unwrap();
+    let nombres: Vec<String> = left.col_fil("nombre").unwrap().collect();
+    assert_eq!(left.columns.iter().collect::<Vec<&str>>(), vec!["anio", "clave", "monto", "nombre", "monto_cat"]);
+    assert_eq!(nombres, vec!["uno", "dos", "dos bis", "uno", "", ""]);
+    assert_eq!(left.records[0].get(1), Some("01"));
+
+    let right = ventas.join_with(&catalogo, &["anio", "clave"], JoinType::Right, &opciones).unwrap();
+    let claves: Vec<&str> = right.records.iter().map(|record| record.get(1).unwrap()).collect();
+    let montos: Vec<&str> = right.records.iter().map(|record| record.get(2).unwrap()).collect();
+    assert_eq!(claves, vec!["01", "1", "2", "2", "5"]);
+    assert_eq!(montos, vec!["10", "7", "5", "5", ""]);
+
+    let outer = ventas.join_with(&catalogo, &["anio", "clave"], JoinType::Outer, &opciones).unwrap();
+    assert_eq!(outer.records.len(), 7);
+    assert_eq!(outer.records[6].iter().collect::<Vec<&str>>(), vec!["2020", "5", "", "cinco", "500"]);
+
+    let semi = ventas.join_with(&catalogo, &["anio", "clave"], JoinType::Semi, &opciones).unwrap();
+    let anti = ventas.join_with(&catalogo, &["anio", "clave"], JoinType::Anti, &opciones).unwrap();
+    assert_eq!(semi.columns, ventas.columns);
+    assert_eq!(semi.col_fil::<i32>("monto").unwrap().collect::<Vec<i32>>(), vec![10, 5, 7]);
+    assert_eq!(anti.col_fil::<i32>("monto").unwrap().collect::<Vec<i32>>(), vec![1, 3]);
+
+    assert!(ventas.join(&catalogo, &["nombre"], JoinType::Inner).is_err());
+    let mala = JoinOptions::new().key_type("monto", DataType::Int);
+    assert!(ventas.join_with(&catalogo, &["clave"], JoinType::Inner, &mala).is_err());
+}
+
+fn get_data() -> (RawFrame, RawFrame) {
+    let ventas = "anio,clave,monto\n2020,01,10\n2020,2,5\n2021,1,7\n2020,,1\n2020,9,3\n".parse().unwrap();
+    let catalogo = "clave,anio,nombre,monto\n1,2020,uno,100\n1,2021,uno,110\n2,2020,dos,200\n2,2020,dos bis,201\n5,2020,cinco,500\n".parse().unwrap();
+    (ventas, catalogo)
+}
@@ -0,0 +1,59 @@
+use ravencol::{Datum, RawFrame};
+use ravencol::numbers::NumberFormat;
+
+#[test]
+fn english_format_test(){
+    let formato = NumberFormat::english().currency(&["$", "USD"]).percent(true);
+
+    assert_eq!(formato.parse::<f64>("1,234.50"), Some(1234.5));
+    assert_eq!(formato.parse::<f64>("$3.20"), Some(3.2));
+    assert_eq!(formato.parse::<f64>("-$1,000"), Some(-1000.0));
+    assert_eq!(formato.parse::<f64>("$-1,000"), Some(-1000.0));
+    assert_eq!(formato.parse::<f64>("25 USD"), Some(25.0));
+    assert_eq!(formato.parse::<i32>("12%"), Some(12));
+    assert_eq!(formato.parse::<f64>("1,2"), None);
+    assert_eq!(formato.parse::<f64>("1,234.5.6"), None);
+    assert_eq!(formato.parse::<f64>("25a"), None);
+    assert_eq!(formato.parse::<f64>("--5"), None);
+    assert_eq!(formato.parse::<f64>("- -5"), None);
+    assert_eq!(formato.parse::<f64>("-$-5"), None);
+    assert_eq!(formato.parse::<f64>("+-5"), None);
+    assert_eq!(formato.normalize("-$-5"), None);
+}
+
+#[test]
+fn european_format_test(){
+    let formato = NumberFormat::european();
+
+    assert_eq!(formato.parse::<f64>("1.234,50"), Some(1234.5));
+    assert_eq!(formato.parse::<f64>("0,5"), Some(0.5));
+    assert_eq!(formato.parse::<i64>("1.234.567"), Some(1234567));
+    assert_eq!(formato.parse::<f64>("1.5"), None);
+}
+
+#[test]
+fn plain_format_test(){
+    let formato = NumberFormat::new();
+
+    assert!(formato.is_plain());
+    assert_eq!(formato.parse::<f64>("1234.5"), Some(1234.5));
+    assert_eq!(formato.parse::<f64>("1,234.5"), None);
+}
+
+#[test]
+fn accessors_with_format_test(){
+    let datos = RawFrame::builder().delimiter(b';').read_str("monto;nombre\n\"1,234.50\";a\n$20;b\nNA;c\n").unwrap();
+    let formato = NumberFormat::english().currency(&["$"]);
+
+    let col: Vec<Option<f64>> = datos.col_type_with("monto", &formato).unwrap().collect();
+    assert_eq!(col, vec![Some(1234.5), Some(20.0), None]);
+
+    let imp: Vec<f64> = datos.col_imp_with("monto", 0.0, &formato).unwrap().collect();
+    assert_eq!(imp, vec![1234.5, 20.0, 0.0]);
+
+    let fil: Vec<i32> = datos.col_fil_with("monto", &formato).unwrap().collect();
+    assert_eq!(fil, vec![20]);
+
+    let datums: Vec<Datum> = datos.column_with("monto", &formato).unwrap().collect();
+    assert_eq!(datums, vec![Datum::Float(1234.5), Datum::Integer(20), Datum::NotNumber("NA")]);
+}
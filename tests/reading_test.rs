@@ -0,0 +1,240 @@
+use ravencol::{Datum, RawFrame};
+use ravencol::reading::{BadRowPolicy, ReadOptions, Trim};
+use std::ffi::OsString;
+
+#[test]
+fn options_semicolon_test(){
+    let datos = get_data(RawFrame::builder().skip_rows(2).delimiter(b';').comment(Some(b'#')));
+
+    assert_eq!(datos.columns.get(0),Some("region"));
+    assert_eq!(datos.columns.get(1),Some("total"));
+    assert_eq!(datos.records.len(),3);
+    assert_eq!(datos.records[0].get(1),Some("10"));
+    assert_eq!(datos.records[2].get(0),Some("este"));
+}
+
+#[test]
+fn options_no_header_test(){
+    let datos = get_data(RawFrame::builder().skip_rows(3).delimiter(b';').has_headers(false).trim(Trim::None));
+
+    assert_eq!(datos.columns.get(0),Some("col_0"));
+    assert_eq!(datos.columns.get(1),Some("col_1"));
+    assert_eq!(datos.records[0].get(1),Some(" 10"));
+    assert_eq!(datos.col_index("col_1"),Some(1));
+}
+
+#[test]
+fn options_max_rows_test(){
+    let datos = get_data(RawFrame::builder().skip_rows(2).delimiter(b';').max_rows(Some(1)));
+
+    assert_eq!(datos.records.len(),1);
+    assert_eq!(datos.records[0].get(0),Some("norte"));
+}
+
+#[test]
+fn options_default_test(){
+    let path = OsString::from("./datos_test/test.csv");
+    let datos = RawFrame::from_os_string_with(path, &ReadOptions::default()).unwrap();
+
+    assert_eq!(datos.records.len(),5);
+    assert_eq!(datos.records[0].get(1),Some("18"));
+}
+
+fn get_data(options: &ReadOptions) -> ravencol::RawFrame {
+    let path = OsString::from("./datos_test/test_semicolon.csv");
+    options.read_os_string(path).unwrap()
+}
+
+#[test]
+fn from_reader_test(){
+    let file = std::fs::File::open("./datos_test/test.csv").unwrap();
+    let datos = RawFrame::from_reader(file).unwrap();
+
+    assert_eq!(datos.columns.get(1),Some("col_b"));
+    assert_eq!(datos.records.len(),5);
+}
+
+#[test]
+fn from_str_test(){
+    let datos: RawFrame = "col_a,col_b\n1, 2\n3\n".parse().unwrap();
+
+    assert_eq!(datos.records[0].get(1),Some("2"));
+    assert_eq!(datos.records[1].get(1),None);
+}
+
+#[test]
+fn read_str_options_test(){
+    let datos = RawFrame::builder().delimiter(b'\t').has_headers(false).read_str("1\t2\n3\t4\n").unwrap();
+
+    let col: Vec<i32> = datos.col_fil("col_1").unwrap().collect();
+
+    assert_eq!(col,vec![2,4]);
+}
+
+#[test]
+fn report_bad_utf8_test(){
+    let data: &[u8] = b"col_a,col_b\n1,2\n3,\xff\n5,6\n";
+    let (datos, report) = RawFrame::builder().read_reader_report(data).unwrap();
+
+    assert_eq!(datos.records.len(),2);
+    assert_eq!(report.bad_rows.len(),1);
+    assert_eq!(report.bad_rows[0].line,3);
+    assert_eq!(report.bad_rows[0].byte,16);
+    assert!(report.quarantine.is_none());
+}
+
+#[test]
+fn report_unequal_lengths_test(){
+    let data = "col_a,col_b\n1,2\n3\n5,6,7\n8,9\n";
+    let (datos, report) = RawFrame::builder().flexible(false).read_reader_report(data.as_bytes()).unwrap();
+
+    assert_eq!(datos.records.len(),2);
+    assert_eq!(report.bad_rows.iter().map(|bad| bad.line).collect::<Vec<u64>>(),vec![3,4]);
+    assert!(!report.is_clean());
+}
+
+#[test]
+fn report_fail_test(){
+    let data = "col_a,col_b\n1,2\n3\n";
+    let resultado = RawFrame::builder().flexible(false).bad_rows(BadRowPolicy::Fail).read_str(data);
+
+    assert!(resultado.is_err());
+}
+
+#[test]
+fn report_quarantine_test(){
+    let data: &[u8] = b"col_a,col_b\n1,2\n3,\xff\n";
+    let (datos, report) = RawFrame::builder().bad_rows(BadRowPolicy::Quarantine).read_reader_report(data).unwrap();
+    let cuarentena = report.quarantine.unwrap();
+
+    assert_eq!(datos.records.len(),1);
+    assert_eq!(cuarentena.columns.get(1),Some("col_b"));
+    assert_eq!(cuarentena.records[0].get(0),Some("3"));
+    assert_eq!(cuarentena.records[0].get(1),Some("\u{FFFD}"));
+}
+
+#[test]
+fn lossy_test(){
+    let data: &[u8] = b"col_a,col_b\n1,2\n3,\xff\n";
+    let (datos, report) = RawFrame::builder().lossy(true).read_reader_report(data).unwrap();
+
+    assert_eq!(datos.records.len(),2);
+    assert!(report.is_clean());
+}
+
+#[test]
+fn null_values_test(){
+    let datos = RawFrame::builder()
+        .null_values(&["NA", "N/A", "-", "null", "#N/D"])
+        .column_null_values("codigo", &["NA"])
+        .read_str("valor,codigo\nNA,-\n25a,NA\n#N/D,7\n3,null\n")
+        .unwrap();
+
+    let valores: Vec<Option<i32>> = datos.col_type("valor").unwrap().collect();
+    assert_eq!(valores, vec![None, None, None, Some(3)]);
+
+    let counts = datos.col_counts::<i32>("valor").unwrap();
+    assert_eq!((counts.valid, counts.null, counts.invalid), (1, 2, 1));
+
+    let datums: Vec<Datum> = datos.column("valor").unwrap().collect();
+    assert_eq!(datums[0], Datum::Empty);
+
+    let codigos: Vec<&str> = datos.records.iter().map(|record| record.get(1).unwrap()).collect();
+    assert_eq!(codigos, vec!["-", "", "7", "null"]);
+
+    let error = RawFrame::builder().column_null_values("otra", &["NA"]).read_str("valor\n1\n").unwrap_err();
+    assert!(matches!(error, ravencol::RavenError::ColumnNotFound { .. }));
+}
+
+#[test]
+fn glob_test(){
+    let (datos, report) = RawFrame::builder()
+        .source_column(Some("source_file"))
+        .read_glob("./datos_test/mensual/2020_0[12].csv")
+        .unwrap();
+
+    assert!(report.is_clean());
+    assert_eq!(report.loaded.len(), 2);
+    assert_eq!(datos.columns.iter().collect::<Vec<&str>>(), vec!["fecha", "ventas", "source_file"]);
+    assert_eq!(datos.records[2].iter().collect::<Vec<&str>>(), vec!["2020-02-01", "20", "./datos_test/mensual/2020_02.csv"]);
+
+    assert!(matches!(RawFrame::from_glob("./datos_test/*/nada_*.csv"), Err(ravencol::RavenError::NoFilesFound { .. })));
+}
+
+#[test]
+fn source_column_test(){
+    let (datos, report) = RawFrame::builder()
+        .source_column(Some("source_file"))
+        .read_glob("./datos_test/irregular.csv")
+        .unwrap();
+
+    assert!(report.is_clean());
+    assert_eq!(datos.records[0].iter().collect::<Vec<&str>>(), vec!["2020-04-01", "3", "./datos_test/irregular.csv", "extra"]);
+    assert_eq!(datos.records[1].iter().collect::<Vec<&str>>(), vec!["2020-04-02", "", "./datos_test/irregular.csv"]);
+
+    let (_, report) = RawFrame::builder()
+        .source_column(Some("tienda"))
+        .read_glob("./datos_test/mensual/*.csv")
+        .unwrap();
+
+    assert_eq!(report.failed.len(), 1);
+    assert!(matches!(report.failed[0].error, ravencol::RavenError::DuplicateColumn { .. }));
+}
+
+#[test]
+fn dir_test(){
+    let (datos, report) = RawFrame::from_dir(OsString::from("./datos_test/mensual")).unwrap();
+
+    assert_eq!(report.loaded.len(), 3);
+    assert_eq!(datos.records.len(), 4);
+    let tiendas: Vec<Option<String>> = datos.col_type("tienda").unwrap().collect();
+    assert_eq!(tiendas[3], Some(String::from("norte")));
+    assert_eq!(datos.records[0].get(2), Some(""));
+
+    let (datos, report) = RawFrame::builder()
+        .concat_mode(ravencol::ConcatMode::Strict)
+        .threads(1)
+        .read_dir(OsString::from("./datos_test/mensual"))
+        .unwrap();
+
+    assert_eq!(datos.records.len(), 3);
+    assert_eq!(report.failed.len(), 1);
+    assert!(report.failed[0].path.ends_with("2020_03.csv"));
+}
+
+struct Interrumpido;
+
+impl std::io::Read for Interrumpido {
+    fn read(&mut self, _buf: &mut [u8]) -> std::io::Result<usize> {
+        Err(std::io::Error::new(std::io::ErrorKind::BrokenPipe, "disco desconectado"))
+    }
+}
+
+#[test]
+fn report_read_error_test(){
+    use std::io::Read;
+
+    let data = "col_a,col_b\n1,2\n".as_bytes().chain(Interrumpido);
+    let (datos, report) = RawFrame::builder().bad_rows(BadRowPolicy::Skip).read_reader_report(data).unwrap();
+
+    assert_eq!(datos.records.len(),1);
+    assert_eq!(report.bad_rows.len(),1);
+    assert!(report.bad_rows[0].error.contains("disco desconectado"));
+
+    let data = "col_a,col_b\n1,2\n".as_bytes().chain(Interrumpido);
+    let resultado = RawFrame::builder().bad_rows(BadRowPolicy::Fail).read_reader_report(data);
+
+    assert!(resultado.is_err());
+}
+
+#[test]
+fn report_width_message_test(){
+    let data = "col_a,col_b\n1,2\n3\n";
+    let (_, report) = RawFrame::builder().flexible(false).read_reader_report(data.as_bytes()).unwrap();
+
+    assert!(report.bad_rows[0].error.contains("the header has 2 fields"));
+
+    let (_, report) = RawFrame::builder().flexible(false).has_headers(false).read_reader_report(data.as_bytes()).unwrap();
+
+    assert!(report.bad_rows[0].error.contains("the first record has 2 fields"));
+}
@@ -0,0 +1,60 @@
+use ravencol::RawFrame;
+use ravencol::aggregate::Aggregation;
+use ravencol::dates::Date;
+use ravencol::resample::{Frequency, GapFill};
+
+fn fila(datos: &RawFrame, n: usize) -> Vec<&str> {
+    datos.records[n].iter().collect()
+}
+
+#[test]
+fn frequency_test(){
+    let fecha = Date::new(2020, 11, 19).unwrap();
+
+    assert_eq!(Frequency::Week.start(fecha), Date::new(2020, 11, 16).unwrap());
+    assert_eq!(Frequency::Month.next(fecha), Date::new(2020, 12, 1).unwrap());
+    assert_eq!(Frequency::Quarter.start(fecha), Date::new(2020, 10, 1).unwrap());
+    assert_eq!(Frequency::Quarter.next(fecha), Date::new(2021, 1, 1).unwrap());
+    assert_eq!(Frequency::Day.next(Date::new(2020, 2, 28).unwrap()), Date::new(2020, 2, 29).unwrap());
+}
+
+#[test]
+fn monthly_rollup_test(){
+    let datos: RawFrame = "fecha,ventas,clientes\n15/01/2020,10,1\n20/01/2020,x,2\n02/03/2020,7,\n31/01/2020,5,4\n".parse().unwrap();
+
+    let aggs = [("ventas", Aggregation::Sum), ("ventas", Aggregation::Last), ("clientes", Aggregation::Count)];
+    let mensual = datos.resample("fecha", Some("%d/%m/%Y"), Frequency::Month, &aggs, GapFill::Omit).unwrap();
+
+    assert_eq!(mensual.columns.iter().collect::<Vec<&str>>(), vec!["fecha", "ventas_sum", "ventas_last", "clientes_count"]);
+    assert_eq!(mensual.records.len(), 2);
+    assert_eq!(fila(&mensual, 0), vec!["2020-01-01", "15", "5", "3"]);
+    assert_eq!(fila(&mensual, 1), vec!["2020-03-01", "7", "7", "0"]);
+}
+
+#[test]
+fn gap_fill_test(){
+    let datos: RawFrame = "fecha,ventas\n2020-01-01,1\n2020-01-04,2\nmal,3\n".parse().unwrap();
+
+    let diario = datos.resample("fecha", None, Frequency::Day, &[("ventas", Aggregation::Mean)], GapFill::Null).unwrap();
+    assert_eq!(diario.records.len(), 4);
+    assert_eq!(fila(&diario, 1), vec!["2020-01-02", ""]);
+
+    let diario = datos.resample("fecha", None, Frequency::Day, &[("ventas", Aggregation::Max)], GapFill::Value(0.0)).unwrap();
+    assert_eq!(fila(&diario, 2), vec!["2020-01-03", "0"]);
+    assert_eq!(fila(&diario, 3), vec!["2020-01-04", "2"]);
+
+    let aggs = [("ventas", Aggregation::Mean), ("ventas", Aggregation::Count)];
+    let diario = datos.resample("fecha", None, Frequency::Day, &aggs, GapFill::Null).unwrap();
+    assert_eq!(fila(&diario, 1), vec!["2020-01-02", "", "0"]);
+
+    let diario = datos.resample("fecha", None, Frequency::Day, &aggs, GapFill::Value(-1.0)).unwrap();
+    assert_eq!(fila(&diario, 2), vec!["2020-01-03", "-1", "0"]);
+}
+
+#[test]
+fn missing_column_test(){
+    let datos: RawFrame = "fecha,ventas\n2020-01-01,1\n".parse().unwrap();
+
+    assert!(datos.resample("fecha", None, Frequency::Day, &[("otra", Aggregation::Sum)], GapFill::Omit).is_err());
+    assert!(datos.resample("dia", None, Frequency::Day, &[("ventas", Aggregation::Sum)], GapFill::Omit).is_err());
+}
@@ -0,0 +1,50 @@
+use ravencol::RawFrame;
+use ravencol::aggregate::Aggregation;
+
+#[test]
+fn melt_test(){
+    let ancho: RawFrame = "estado,region,2019,2020\nColima,occidente,5,7\nOaxaca,sur,3\n".parse().unwrap();
+
+    let largo = ancho.melt(&["estado"], &["2019", "2020"], "anio", "valor").unwrap();
+    assert_eq!(largo.columns.iter().collect::<Vec<&str>>(), vec!["estado", "anio", "valor"]);
+    assert_eq!(largo.records.len(), 4);
+    assert_eq!(largo.records[3].iter().collect::<Vec<&str>>(), vec!["Oaxaca", "2020", ""]);
+
+    let todas = ancho.melt(&["estado"], &[], "variable", "valor").unwrap();
+    let variables: Vec<String> = todas.col_fil("variable").unwrap().collect();
+    assert_eq!(variables, vec!["region", "region", "2019", "2019", "2020", "2020"]);
+
+    assert!(ancho.melt(&["estado"], &["2021"], "anio", "valor").is_err());
+    assert!(ancho.melt(&["estado"], &[], "estado", "valor").is_err());
+}
+
+#[test]
+fn pivot_test(){
+    let ventas = get_data();
+
+    let maximo = ventas.pivot(&["region"], "producto", "ventas", Aggregation::Max).unwrap();
+    assert_eq!(maximo.columns.iter().collect::<Vec<&str>>(), vec!["region", "a", "b", "c"]);
+    assert_eq!(maximo.records[0].iter().collect::<Vec<&str>>(), vec!["sur", "10", "4", ""]);
+    assert_eq!(maximo.records[1].iter().collect::<Vec<&str>>(), vec!["norte", "", "", "1"]);
+
+    let conteo = ventas.pivot(&["region"], "producto", "ventas", Aggregation::Count).unwrap();
+    assert_eq!(conteo.records[0].iter().collect::<Vec<&str>>(), vec!["sur", "2", "1", ""]);
+    assert_eq!(conteo.records[1].iter().collect::<Vec<&str>>(), vec!["norte", "", "0", "1"]);
+
+    let texto: RawFrame = "estado,campo,valor\nColima,clave,006\nColima,capital,Colima\nColima,clave,06\nOaxaca,capital,\nOaxaca,clave,1.50\n".parse().unwrap();
+    let primero = texto.pivot(&["estado"], "campo", "valor", Aggregation::First).unwrap();
+    assert_eq!(primero.records[0].iter().collect::<Vec<&str>>(), vec!["Colima", "006", "Colima"]);
+    assert_eq!(primero.records[1].iter().collect::<Vec<&str>>(), vec!["Oaxaca", "1.50", ""]);
+    let ultimo = texto.pivot(&["estado"], "campo", "valor", Aggregation::Last).unwrap();
+    assert_eq!(ultimo.records[0].iter().collect::<Vec<&str>>(), vec!["Colima", "06", "Colima"]);
+    let suma = texto.pivot(&["estado"], "campo", "valor", Aggregation::Sum).unwrap();
+    assert_eq!(suma.records[1].iter().collect::<Vec<&str>>(), vec!["Oaxaca", "1.5", ""]);
+
+    let choque: RawFrame = "region,producto,ventas\nsur,region,1\n".parse().unwrap();
+    assert!(choque.pivot(&["region"], "producto", "ventas", Aggregation::Sum).is_err());
+    assert!(ventas.pivot(&["region"], "tienda", "ventas", Aggregation::Sum).is_err());
+}
+
+fn get_data() -> RawFrame {
+    "region,producto,ventas\nsur,a,10\nsur,b,4\nsur,a,6\nnorte,b,x\nnorte,c,1\n".parse().unwrap()
+}
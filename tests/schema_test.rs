@@ -0,0 +1,64 @@
+use ravencol::RawFrame;
+use ravencol::dataframe::DataType;
+use ravencol::dates::Date;
+use ravencol::schema::InferMode;
+
+#[test]
+fn infer_types_test(){
+    let datos = get_data();
+    let schema = datos.infer_schema(None);
+
+    assert_eq!(schema.types(),vec![("id",DataType::Int),("monto",DataType::Int),("activo",DataType::Bool),("fecha",DataType::Date),("nombre",DataType::Str)]);
+}
+
+#[test]
+fn infer_nullable_test(){
+    let datos = get_data();
+    let schema = datos.infer_schema(None);
+
+    assert!(!schema.get("id").unwrap().nullable);
+    assert!(schema.get("fecha").unwrap().nullable);
+    assert_eq!(schema.get("fecha").unwrap().null_count,1);
+    assert!(schema.get("nombre").unwrap().nullable);
+}
+
+#[test]
+fn infer_ratio_test(){
+    let datos = get_data();
+
+    let strict = datos.infer_schema(None);
+    assert_eq!(strict.get("monto").unwrap().invalid_ratio,0.25);
+    assert_eq!(strict.get("activo").unwrap().invalid_ratio,0.25);
+
+    let promoted = datos.infer_schema_with(None, InferMode::PromoteInts);
+    assert_eq!(promoted.get("monto").unwrap().data_type,DataType::Float);
+    assert_eq!(promoted.get("monto").unwrap().invalid_ratio,0.0);
+}
+
+#[test]
+fn infer_sample_test(){
+    let datos = get_data();
+    let schema = datos.infer_schema(Some(1));
+
+    assert_eq!(schema.get("monto").unwrap().invalid_ratio,0.0);
+    assert!(!schema.get("fecha").unwrap().nullable);
+}
+
+#[test]
+fn infer_to_dataframe_test(){
+    let datos = get_data();
+    let schema = datos.infer_schema(None);
+
+    let frame = datos.to_dataframe(&schema.types()).unwrap();
+
+    assert_eq!(frame.date("fecha").unwrap().get(0),Date::new(2021,1,31).as_ref());
+    assert_eq!(frame.boolean("activo").unwrap().null_count(),1);
+}
+
+fn get_data() -> RawFrame {
+    "id,monto,activo,fecha,nombre\n\
+     1,10,true,2021-01-31,ana\n\
+     2,12.5,false,2021-02-28,\n\
+     3,7,si,,luis\n\
+     4,8,true,2021-03-01,eva\n".parse().unwrap()
+}
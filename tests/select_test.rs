@@ -0,0 +1,46 @@
+use ravencol::RawFrame;
+
+#[test]
+fn select_drop_test(){
+    let datos = get_data();
+
+    let seleccion = datos.select(&["poblacion", "estado"]).unwrap();
+    assert_eq!(seleccion.columns.iter().collect::<Vec<&str>>(), vec!["poblacion", "estado"]);
+    assert_eq!(seleccion.records[0].iter().collect::<Vec<&str>>(), vec!["9209944", "CDMX"]);
+
+    let sin_region = datos.drop(&["region"]).unwrap();
+    assert_eq!(sin_region.columns.iter().collect::<Vec<&str>>(), vec!["estado", "poblacion"]);
+    assert_eq!(sin_region.records[3].iter().collect::<Vec<&str>>(), vec!["Oaxaca", "NA"]);
+
+    assert!(matches!(datos.select(&["estado", "estado"]), Err(ravencol::RavenError::DuplicateColumn { .. })));
+    assert!(matches!(datos.drop(&["otra"]), Err(ravencol::RavenError::ColumnsNotFound { .. })));
+}
+
+#[test]
+fn rename_reorder_test(){
+    let datos = get_data();
+
+    let renombrado = datos.rename("poblacion", "habitantes").unwrap();
+    assert_eq!(renombrado.col_index("habitantes"), Some(2));
+    assert!(datos.rename("poblacion", "estado").is_err());
+    assert!(datos.rename("otra", "nueva").is_err());
+
+    let ordenado = datos.reorder(&["poblacion"]).unwrap();
+    assert_eq!(ordenado.columns.iter().collect::<Vec<&str>>(), vec!["poblacion", "estado", "region"]);
+    assert_eq!(ordenado.records[1].iter().collect::<Vec<&str>>(), vec!["731391", "Colima", "occidente"]);
+}
+
+#[test]
+fn project_short_records_test(){
+    let datos: RawFrame = "a,b,c\n1,2,3\n4\n".parse().unwrap();
+
+    let ordenado = datos.select(&["c", "a"]).unwrap();
+    assert_eq!(ordenado.records[1].iter().collect::<Vec<&str>>(), vec!["", "4"]);
+
+    let seleccion = datos.select(&["a", "c"]).unwrap();
+    assert_eq!(seleccion.records[1].len(), 1);
+}
+
+fn get_data() -> RawFrame {
+    "estado,region,poblacion\nCDMX,centro,9209944\nColima,occidente,731391\nJalisco,occidente,8348151\nOaxaca,sur,NA\n".parse().unwrap()
+}
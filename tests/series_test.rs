@@ -0,0 +1,80 @@
+use ravencol::RawFrame;
+use ravencol::RavenError;
+use ravencol::dataframe::{Column, DataFrame, DataType};
+use ravencol::series::{Bitmap, Series};
+use std::ffi::OsString;
+
+#[test]
+fn bitmap_test(){
+    let mut bitmap: Bitmap = (0..130).map(|n| n % 3 == 0).collect();
+
+    assert_eq!(bitmap.len(),130);
+    assert_eq!(bitmap.count_ones(),44);
+    assert_eq!(bitmap.get(129),Some(true));
+    assert_eq!(bitmap.get(130),None);
+
+    bitmap.set(129,false);
+    assert_eq!(bitmap.count_ones(),43);
+    assert_eq!(Bitmap::with_value(70,true).count_ones(),70);
+}
+
+#[test]
+fn series_test(){
+    let datos = get_data();
+
+    let serie: Series<i32> = datos.series("col_a").unwrap();
+    let col_type: Vec<Option<i32>> = datos.col_type("col_a").unwrap().collect();
+    let col_fil: Vec<i32> = datos.col_fil("col_a").unwrap().collect();
+    let col_imp: Vec<i32> = datos.col_imp("col_a",0).unwrap().collect();
+
+    assert_eq!(serie.name(),"col_a");
+    assert_eq!(serie.to_options(),col_type);
+    assert_eq!(serie.iter_valid().cloned().collect::<Vec<i32>>(),col_fil);
+    assert_eq!(serie.iter_imp(0).collect::<Vec<i32>>(),col_imp);
+    assert_eq!(serie.null_count(),1);
+    assert!(!serie.is_valid(0));
+}
+
+#[test]
+fn dataframe_test(){
+    let datos = get_data();
+
+    let frame = DataFrame::from_raw(&datos, &[("col_b", DataType::Str), ("col_a", DataType::Float)]).unwrap();
+
+    assert_eq!(frame.names(),vec!["col_b","col_a"]);
+    assert_eq!(frame.schema(),vec![("col_b",DataType::Str),("col_a",DataType::Float)]);
+    assert_eq!(frame.string("col_b").unwrap().get(1),Some(&String::from("25a")));
+    assert_eq!(frame.string("col_b").unwrap().get(3),None);
+    assert!(matches!(frame.int("col_a"), Err(RavenError::TypeMismatch { .. })));
+    assert!(matches!(frame.float("col_z"), Err(RavenError::ColumnNotFound { .. })));
+}
+
+#[test]
+fn dataframe_roundtrip_test(){
+    let datos: RawFrame = "id,valor,activo\n1,2.5,true\n2,,false\n3,4,x\n".parse().unwrap();
+
+    let frame = datos.to_dataframe(&[("id", DataType::Int), ("valor", DataType::Float), ("activo", DataType::Bool)]).unwrap();
+    let raw = frame.to_raw();
+
+    assert_eq!(raw.columns,datos.columns);
+    assert_eq!(raw.records[0].iter().collect::<Vec<&str>>(),vec!["1","2.5","true"]);
+    assert_eq!(raw.records[1].iter().collect::<Vec<&str>>(),vec!["2","","false"]);
+    assert_eq!(raw.records[2].iter().collect::<Vec<&str>>(),vec!["3","4",""]);
+}
+
+#[test]
+fn with_column_test(){
+    let mut frame = DataFrame::new();
+
+    frame.with_column(Column::Int(Series::new("a", vec![1,2,3]))).unwrap();
+    frame.with_column(Column::Float(Series::from_options("b", vec![Some(1.0),None,Some(3.0)]))).unwrap();
+
+    assert_eq!(frame.ncols(),2);
+    assert_eq!(frame.column("b").unwrap().null_count(),1);
+    assert!(matches!(frame.with_column(Column::Bool(Series::new("c", vec![true]))), Err(RavenError::LengthMismatch { expected: 3, found: 1 })));
+}
+
+fn get_data() -> RawFrame {
+    let path = OsString::from("./datos_test/test.csv");
+    RawFrame::from_os_string(path).unwrap()
+}
@@ -0,0 +1,28 @@
+use ravencol::RawFrame;
+use ravencol::dataframe::DataType;
+use ravencol::sort::SortKey;
+
+#[test]
+fn sort_by_test(){
+    let datos = get_data();
+
+    let ids = |datos: &RawFrame| datos.col_fil::<String>("id").unwrap().collect::<Vec<String>>();
+
+    let numerico = datos.sort_by(&[SortKey::new("valor", DataType::Float)]).unwrap();
+    assert_eq!(ids(&numerico), vec!["b", "a", "d", "c", "e"]);
+
+    let texto = datos.sort_by(&[SortKey::new("valor", DataType::Str).nulls_first()]).unwrap();
+    assert_eq!(ids(&texto), vec!["e", "a", "d", "b", "c"]);
+
+    let fechas = datos.sort_by(&[SortKey::new("fecha", DataType::Date).descending(), SortKey::new("id", DataType::Str).descending()]).unwrap();
+    assert_eq!(ids(&fechas), vec!["a", "d", "b", "e", "c"]);
+
+    let estable = datos.sort_by(&[SortKey::new("fecha", DataType::Date)]).unwrap();
+    assert_eq!(ids(&estable), vec!["e", "b", "d", "a", "c"]);
+
+    assert!(datos.sort_by(&[SortKey::new("otra", DataType::Int)]).is_err());
+}
+
+fn get_data() -> RawFrame {
+    "id,valor,fecha\na,10,2020-03-01\nb,9,2020-01-15\nc,NaN,\nd,100,2020-01-15\ne,,2019-12-31\n".parse().unwrap()
+}
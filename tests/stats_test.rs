@@ -0,0 +1,111 @@
+use ravencol::{RawFrame, RavenError};
+use ravencol::correlation::{CorrMethod, MissingPolicy};
+use ravencol::stats::{QuantileMethod, Stats};
+
+fn get_data() -> RawFrame {
+    "x,y\n4,1.5\n1,NaN\n3,\n2,2.5\nNA,x\n".parse().unwrap()
+}
+
+#[test]
+fn frame_reductions_test(){
+    let datos = get_data();
+
+    assert_eq!(datos.mean_fil::<i32>("x").unwrap(), 2.5);
+    assert_eq!(datos.mean_imp("x", 10).unwrap(), 4.0);
+    assert_eq!(datos.variance_fil::<i32>("x").unwrap(), 5.0 / 3.0);
+    assert_eq!(datos.std_imp("x", 2.5).unwrap(), 1.25f64.sqrt());
+    assert_eq!(datos.median_fil::<i64>("x").unwrap(), 2.5);
+    assert_eq!(datos.mode_imp("x", 3).unwrap(), 3);
+    assert_eq!(datos.sum_fil::<i32>("x").unwrap(), 10.0);
+    assert_eq!(datos.product_imp("x", 1).unwrap(), 24.0);
+
+    assert_eq!(datos.mean_fil::<f64>("y").unwrap(), 2.0);
+    assert_eq!(datos.median_imp("y", 0.0).unwrap(), 0.75);
+
+    assert!(matches!(datos.quantile_fil::<f64>("x", -0.1, QuantileMethod::Linear), Err(RavenError::InvalidQuantile { .. })));
+    assert!(matches!(datos.variance_fil::<f64>("otra"), Err(RavenError::ColumnNotFound { .. })));
+
+    let vacio: RawFrame = "x\nNA\n".parse().unwrap();
+    assert!(matches!(vacio.mean_fil::<f64>("x"), Err(RavenError::EmptyColumn { .. })));
+    assert_eq!(vacio.sum_fil::<f64>("x").unwrap(), 0.0);
+
+    let grandes: RawFrame = "x\n2000000000\n2000000000\n".parse().unwrap();
+    assert_eq!(grandes.sum_fil::<i32>("x").unwrap(), 4e9);
+    assert_eq!(grandes.product_imp("x", 1).unwrap(), 4e18);
+}
+
+#[test]
+fn quantile_methods_test(){
+    let valores = [1.0, 2.0, 3.0, 4.0];
+
+    assert_eq!(valores.iter().copied().quantile(0.5, QuantileMethod::Linear), Some(2.5));
+    assert_eq!(valores.iter().copied().quantile(0.5, QuantileMethod::Lower), Some(2.0));
+    assert_eq!(valores.iter().copied().quantile(0.5, QuantileMethod::Higher), Some(3.0));
+    assert_eq!(valores.iter().copied().quantile(0.5, QuantileMethod::Midpoint), Some(2.5));
+    assert_eq!(valores.iter().copied().quantile(0.5, QuantileMethod::Nearest), Some(3.0));
+    assert_eq!(valores.iter().copied().quantile(0.4, QuantileMethod::Nearest), Some(2.0));
+    assert_eq!([1.0, 2.0, 3.0, 4.0, 5.0].iter().copied().quantile(0.625, QuantileMethod::Nearest), Some(3.0));
+    assert_eq!([1.0, 2.0, 3.0, 4.0, 5.0].iter().copied().quantile(0.875, QuantileMethod::Nearest), Some(5.0));
+    assert_eq!(valores.iter().copied().quantile(0.0, QuantileMethod::Linear), Some(1.0));
+    assert_eq!(valores.iter().copied().quantile(1.0, QuantileMethod::Linear), Some(4.0));
+    assert_eq!(valores.iter().copied().quantile(1.1, QuantileMethod::Linear), None);
+}
+
+#[test]
+fn iterator_extension_test(){
+    let datos = get_data();
+
+    let y = datos.col_fil::<f64>("y").unwrap();
+    assert_eq!(y.mean(), Some(2.0));
+
+    assert_eq!(vec![3u8, 1, 3, 1].into_iter().mode(), Some(1));
+    assert_eq!(vec![9007199254740993i64, 9007199254740992, 9007199254740993, 9007199254740992, 9007199254740992].into_iter().mode(), Some(9007199254740992));
+    assert_eq!(vec![2.0f32].into_iter().variance(), None);
+    assert_eq!(Vec::<i32>::new().into_iter().median(), None);
+
+    let grandes = vec![1e9 + 4.0, 1e9 + 7.0, 1e9 + 13.0, 1e9 + 16.0];
+    assert_eq!(grandes.into_iter().variance(), Some(30.0));
+}
+
+#[test]
+fn corr_cov_test(){
+    let datos: RawFrame = "a,b,c,d\n1,3,10,5\n2,1,,5\n3,4,30,5\n4,1,20,5\n5,5,x,5\n".parse().unwrap();
+
+    let pearson = datos.corr(&["a", "b", "c", "d"], CorrMethod::Pearson).unwrap();
+    assert_eq!(pearson.labels, vec!["a", "b", "c", "d"]);
+    assert_eq!(pearson.get("a", "a"), Some(1.0));
+    assert!((pearson.get("a", "b").unwrap() - 0.35355339059327373).abs() < 1e-12);
+    assert_eq!(pearson.get("a", "b"), pearson.get("b", "a"));
+    assert!((pearson.get("a", "c").unwrap() - 0.6546536707079772).abs() < 1e-12);
+    assert_eq!(pearson.get("a", "d"), None);
+    assert_eq!(pearson.get("a", "e"), None);
+
+    let spearman = datos.corr(&["a", "b"], CorrMethod::Spearman).unwrap();
+    assert!((spearman.get("a", "b").unwrap() - 0.41039134083406165).abs() < 1e-12);
+
+    let listwise = datos.corr_with(&["a", "c"], CorrMethod::Spearman, MissingPolicy::Listwise).unwrap();
+    assert!((listwise.get("a", "c").unwrap() - 0.5).abs() < 1e-12);
+
+    let cov = datos.cov(&["a", "c"]).unwrap();
+    assert_eq!(cov.get("a", "a"), Some(2.5));
+    assert_eq!(cov.get("c", "c"), Some(100.0));
+    assert_eq!(cov.get("a", "c"), Some(10.0));
+
+    let listwise = datos.cov_with(&["a", "c"], MissingPolicy::Listwise).unwrap();
+    assert!((listwise.get("a", "a").unwrap() - 7.0 / 3.0).abs() < 1e-12);
+
+    let frame = cov.to_rawframe();
+    assert_eq!(frame.columns.iter().collect::<Vec<&str>>(), vec!["column", "a", "c"]);
+    assert_eq!(frame.records[1].iter().collect::<Vec<&str>>(), vec!["c", "10", "100"]);
+
+    let tabla = cov.to_string();
+    assert_eq!(tabla.lines().next(), Some("         a         c"));
+    assert_eq!(tabla.lines().nth(1), Some("a   2.5000   10.0000"));
+
+    assert!(datos.corr(&["a", "z"], CorrMethod::Pearson).is_err());
+
+    let estados: RawFrame = "estado,x,y\nCDMX,1,2\nColima,2,4\nJalisco,3,7\n".parse().unwrap();
+    let numericas = estados.corr_with(&[], CorrMethod::Pearson, MissingPolicy::Listwise).unwrap();
+    assert_eq!(numericas.labels, vec!["x", "y"]);
+    assert!(numericas.get("x", "y").unwrap() > 0.9);
+}
@@ -0,0 +1,68 @@
+use ravencol::RawFrame;
+use ravencol::RavenError;
+use ravencol::dataframe::DataType;
+use ravencol::validation::{ColumnSpec, DeclaredSchema, Rule, ValidationMode};
+
+#[test]
+fn reject_test(){
+    let (datos, report) = get_data().validate(&get_schema(), ValidationMode::Reject).unwrap();
+
+    assert_eq!(datos.records.len(),1);
+    assert_eq!(report.rejected_rows,3);
+    assert_eq!(report.violations.len(),3);
+
+    let primera = &report.violations[0];
+    assert_eq!((primera.row,primera.line),(1,3));
+    assert_eq!(primera.column,"clave");
+    assert_eq!(primera.value,"X-12");
+    assert!(matches!(primera.rule, Rule::Pattern(_)));
+}
+
+#[test]
+fn coerce_test(){
+    let (datos, report) = get_data().validate(&get_schema(), ValidationMode::CoerceNull).unwrap();
+
+    assert_eq!(datos.records.len(),3);
+    assert_eq!(report.rejected_rows,1);
+    assert_eq!(datos.records[1].get(0),Some(""));
+    assert_eq!(datos.records[2].get(2),Some(""));
+    assert_eq!(datos.records[2].get(1),Some("Puebla"));
+}
+
+#[test]
+fn abort_test(){
+    match get_data().validate(&get_schema(), ValidationMode::Abort) {
+        Err(RavenError::ValidationFailed { line, column, .. }) => {
+            assert_eq!(line,3);
+            assert_eq!(column,"clave");
+        },
+        _ => panic!("validation should abort"),
+    }
+}
+
+#[test]
+fn missing_column_test(){
+    let schema = DeclaredSchema::new().column(ColumnSpec::new("otra", DataType::Int));
+
+    assert!(matches!(get_data().validate(&schema, ValidationMode::Reject), Err(RavenError::ColumnsNotFound { .. })));
+}
+
+#[test]
+fn invalid_pattern_test(){
+    assert!(matches!(ColumnSpec::new("clave", DataType::Str).pattern("(["), Err(RavenError::InvalidPattern { .. })));
+}
+
+fn get_schema() -> DeclaredSchema {
+    DeclaredSchema::new()
+        .column(ColumnSpec::new("clave", DataType::Str).pattern(r"^[A-Z]{2}\d{2}$").unwrap())
+        .column(ColumnSpec::new("estado", DataType::Str).nullable(false).one_of(&["Puebla","Oaxaca"]))
+        .column(ColumnSpec::new("tasa", DataType::Float).range(Some(0.0), Some(1.0)))
+}
+
+fn get_data() -> RawFrame {
+    "clave,estado,tasa\n\
+     AB12,Puebla,0.5\n\
+     X-12,Oaxaca,0.1\n\
+     CD34,,0.2\n\
+     EF56,Puebla,1.5\n".parse().unwrap()
+}